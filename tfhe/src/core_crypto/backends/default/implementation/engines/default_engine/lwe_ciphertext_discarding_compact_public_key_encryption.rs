@@ -0,0 +1,174 @@
+use crate::core_crypto::backends::default::implementation::engines::DefaultEngine;
+use crate::core_crypto::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCompactPublicKey32, LweCompactPublicKey64, Plaintext32,
+    Plaintext64,
+};
+use crate::core_crypto::prelude::Variance;
+use crate::core_crypto::specification::engines::{
+    LweCiphertextDiscardingCompactPublicKeyEncryptionEngine,
+    LweCiphertextDiscardingCompactPublicKeyEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingCompactPublicKeyEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingCompactPublicKeyEncryptionEngine<
+        LweCompactPublicKey32,
+        Plaintext32,
+        LweCiphertext32,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LweCompactPublicKey32 =
+    ///     engine.generate_new_lwe_compact_public_key(&secret_key, noise)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext_container = vec![0u32; lwe_dimension.to_lwe_size().0];
+    /// let mut ciphertext = engine.create_lwe_ciphertext_from(ciphertext_container)?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_with_compact_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_with_compact_public_key(
+        &mut self,
+        key: &LweCompactPublicKey32,
+        output: &mut LweCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingCompactPublicKeyEncryptionError<Self::EngineError>> {
+        LweCiphertextDiscardingCompactPublicKeyEncryptionError::perform_generic_checks(
+            key, output,
+        )?;
+        unsafe {
+            self.discard_encrypt_lwe_ciphertext_with_compact_public_key_unchecked(
+                key, output, input, noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_with_compact_public_key_unchecked(
+        &mut self,
+        key: &LweCompactPublicKey32,
+        output: &mut LweCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) {
+        key.0.encrypt_lwe(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingCompactPublicKeyEncryptionEngine`] for
+/// [`DefaultEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingCompactPublicKeyEncryptionEngine<
+        LweCompactPublicKey64,
+        Plaintext64,
+        LweCiphertext64,
+    > for DefaultEngine
+{
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LweCompactPublicKey64 =
+    ///     engine.generate_new_lwe_compact_public_key(&secret_key, noise)?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let ciphertext_container = vec![0u64; lwe_dimension.to_lwe_size().0];
+    /// let mut ciphertext = engine.create_lwe_ciphertext_from(ciphertext_container)?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_with_compact_public_key(
+    ///     &public_key,
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_with_compact_public_key(
+        &mut self,
+        key: &LweCompactPublicKey64,
+        output: &mut LweCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingCompactPublicKeyEncryptionError<Self::EngineError>> {
+        LweCiphertextDiscardingCompactPublicKeyEncryptionError::perform_generic_checks(
+            key, output,
+        )?;
+        unsafe {
+            self.discard_encrypt_lwe_ciphertext_with_compact_public_key_unchecked(
+                key, output, input, noise,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_with_compact_public_key_unchecked(
+        &mut self,
+        key: &LweCompactPublicKey64,
+        output: &mut LweCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) {
+        key.0.encrypt_lwe(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.secret_generator,
+            &mut self.encryption_generator,
+        );
+    }
+}
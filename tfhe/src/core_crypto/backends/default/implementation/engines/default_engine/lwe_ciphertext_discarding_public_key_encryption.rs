@@ -2,6 +2,7 @@ use crate::core_crypto::backends::default::implementation::engines::DefaultEngin
 use crate::core_crypto::backends::default::implementation::entities::{
     LweCiphertext32, LweCiphertext64, LwePublicKey32, LwePublicKey64, Plaintext32, Plaintext64,
 };
+use crate::core_crypto::prelude::CiphertextCount;
 use crate::core_crypto::specification::engines::{
     LweCiphertextDiscardingPublicKeyEncryptionEngine,
     LweCiphertextDiscardingPublicKeyEncryptionError,
@@ -74,20 +75,12 @@ impl LweCiphertextDiscardingPublicKeyEncryptionEngine<LwePublicKey32, Plaintext3
         output: &mut LweCiphertext32,
         input: &Plaintext32,
     ) {
-        // Fills output masks with zeros, store input in the body
-        output.0.fill_with_trivial_encryption(&input.0);
-        let ct_choice = self
-            .secret_generator
-            .random_binary_tensor::<u32>(key.lwe_zero_encryption_count().0);
-
-        // Add the public encryption of zeros to get the encryption
-        for (&chosen, public_encryption_of_zero) in
-            ct_choice.as_container().iter().zip(key.0.ciphertext_iter())
-        {
-            if chosen == 1 {
-                output.0.update_with_add(&public_encryption_of_zero);
-            }
-        }
+        key.0.encrypt_lwe_with_public_key(
+            &mut output.0,
+            &input.0,
+            CiphertextCount(key.lwe_zero_encryption_count().0),
+            &mut self.secret_generator,
+        );
     }
 }
 
@@ -157,19 +150,11 @@ impl LweCiphertextDiscardingPublicKeyEncryptionEngine<LwePublicKey64, Plaintext6
         output: &mut LweCiphertext64,
         input: &Plaintext64,
     ) {
-        // Fills output masks with zeros, store input in the body
-        output.0.fill_with_trivial_encryption(&input.0);
-        let ct_choice = self
-            .secret_generator
-            .random_binary_tensor::<u64>(key.lwe_zero_encryption_count().0);
-
-        // Add the public encryption of zeros to get the encryption
-        for (&chosen, public_encryption_of_zero) in
-            ct_choice.as_container().iter().zip(key.0.ciphertext_iter())
-        {
-            if chosen == 1 {
-                output.0.update_with_add(&public_encryption_of_zero);
-            }
-        }
+        key.0.encrypt_lwe_with_public_key(
+            &mut output.0,
+            &input.0,
+            CiphertextCount(key.lwe_zero_encryption_count().0),
+            &mut self.secret_generator,
+        );
     }
 }
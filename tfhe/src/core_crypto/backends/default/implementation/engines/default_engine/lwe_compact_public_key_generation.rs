@@ -0,0 +1,116 @@
+use crate::core_crypto::backends::default::engines::DefaultEngine;
+use crate::core_crypto::backends::default::entities::{
+    LweCompactPublicKey32, LweCompactPublicKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::core_crypto::commons::crypto::lwe::LweCompactPublicKey as ImplLweCompactPublicKey;
+use crate::core_crypto::prelude::Variance;
+use crate::core_crypto::specification::engines::{
+    LweCompactPublicKeyGenerationEngine, LweCompactPublicKeyGenerationError,
+};
+use crate::core_crypto::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweCompactPublicKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 32 bits integers.
+impl LweCompactPublicKeyGenerationEngine<LweSecretKey32, LweCompactPublicKey32> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LweCompactPublicKey32 =
+    ///     engine.generate_new_lwe_compact_public_key(&lwe_secret_key, noise)?;
+    ///
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_compact_public_key(
+        &mut self,
+        lwe_secret_key: &LweSecretKey32,
+        noise: Variance,
+    ) -> Result<LweCompactPublicKey32, LweCompactPublicKeyGenerationError<Self::EngineError>> {
+        LweCompactPublicKeyGenerationError::perform_generic_checks(lwe_secret_key.lwe_dimension())?;
+        Ok(unsafe { self.generate_new_lwe_compact_public_key_unchecked(lwe_secret_key, noise) })
+    }
+
+    unsafe fn generate_new_lwe_compact_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &LweSecretKey32,
+        noise: Variance,
+    ) -> LweCompactPublicKey32 {
+        let mut public_key =
+            ImplLweCompactPublicKey::allocate(0u32, lwe_secret_key.lwe_dimension());
+        public_key.fill_with_compact_public_key(
+            &lwe_secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCompactPublicKey32(public_key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCompactPublicKeyGenerationEngine`] for [`DefaultEngine`] that operates
+/// on 64 bits integers.
+impl LweCompactPublicKeyGenerationEngine<LweSecretKey64, LweCompactPublicKey64> for DefaultEngine {
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LweCompactPublicKey64 =
+    ///     engine.generate_new_lwe_compact_public_key(&lwe_secret_key, noise)?;
+    ///
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_new_lwe_compact_public_key(
+        &mut self,
+        lwe_secret_key: &LweSecretKey64,
+        noise: Variance,
+    ) -> Result<LweCompactPublicKey64, LweCompactPublicKeyGenerationError<Self::EngineError>> {
+        LweCompactPublicKeyGenerationError::perform_generic_checks(lwe_secret_key.lwe_dimension())?;
+        Ok(unsafe { self.generate_new_lwe_compact_public_key_unchecked(lwe_secret_key, noise) })
+    }
+
+    unsafe fn generate_new_lwe_compact_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &LweSecretKey64,
+        noise: Variance,
+    ) -> LweCompactPublicKey64 {
+        let mut public_key =
+            ImplLweCompactPublicKey::allocate(0u64, lwe_secret_key.lwe_dimension());
+        public_key.fill_with_compact_public_key(
+            &lwe_secret_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweCompactPublicKey64(public_key)
+    }
+}
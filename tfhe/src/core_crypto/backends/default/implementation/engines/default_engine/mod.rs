@@ -79,6 +79,7 @@ mod lwe_ciphertext_consuming_retrieval;
 mod lwe_ciphertext_creation;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_compact_public_key_encryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_public_key_encryption;
@@ -93,6 +94,7 @@ mod lwe_ciphertext_vector_creation;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_compact_public_key_generation;
 mod lwe_keyswitch_key_generation;
 mod lwe_public_key_generation;
 mod lwe_secret_key_generation;
@@ -5,8 +5,8 @@ use crate::core_crypto::commons::crypto::encoding::{
 };
 use crate::core_crypto::commons::crypto::glwe::LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys;
 use crate::core_crypto::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList,
+    LweCiphertext as ImplLweCiphertext, LweCompactPublicKey as ImplLweCompactPublicKey,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
 };
 use crate::core_crypto::commons::crypto::secret::{
     GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
@@ -21,9 +21,10 @@ use crate::core_crypto::prelude::{
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweKeyswitchKey32,
-    LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version, LwePublicKey32,
-    LwePublicKey32Version, LwePublicKey64, LwePublicKey64Version, LweSecretKey32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweCompactPublicKey32,
+    LweCompactPublicKey32Version, LweCompactPublicKey64, LweCompactPublicKey64Version,
+    LweKeyswitchKey32, LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
+    LwePublicKey32, LwePublicKey32Version, LwePublicKey64, LwePublicKey64Version, LweSecretKey32,
     LweSecretKey32Version, LweSecretKey64, LweSecretKey64Version, Plaintext32, Plaintext32Version,
     Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
     PlaintextVector64, PlaintextVector64Version,
@@ -1175,6 +1176,132 @@ impl EntityDeserializationEngine<&[u8], LwePublicKey64> for DefaultSerialization
     }
 }
 
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 32 bits integers. It deserializes a compact LWE public key.
+impl EntityDeserializationEngine<&[u8], LweCompactPublicKey32> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LweCompactPublicKey32 =
+    ///     engine.generate_new_lwe_compact_public_key(&lwe_secret_key, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompactPublicKey32, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCompactPublicKey32 {
+            version: LweCompactPublicKey32Version,
+            inner: ImplLweCompactPublicKey<Vec<u32>>,
+        }
+        let deserialized: DeserializableLweCompactPublicKey32 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCompactPublicKey32 {
+                version: LweCompactPublicKey32Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCompactPublicKey32 {
+                version: LweCompactPublicKey32Version::V0,
+                inner,
+            } => Ok(LweCompactPublicKey32(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCompactPublicKey32 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
+/// operates on 64 bits integers. It deserializes a compact LWE public key.
+impl EntityDeserializationEngine<&[u8], LweCompactPublicKey64> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LweCompactPublicKey64 =
+    ///     engine.generate_new_lwe_compact_public_key(&lwe_secret_key, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn deserialize(
+        &mut self,
+        serialized: &[u8],
+    ) -> Result<LweCompactPublicKey64, EntityDeserializationError<Self::EngineError>> {
+        #[derive(Deserialize)]
+        struct DeserializableLweCompactPublicKey64 {
+            version: LweCompactPublicKey64Version,
+            inner: ImplLweCompactPublicKey<Vec<u64>>,
+        }
+        let deserialized: DeserializableLweCompactPublicKey64 = bincode::deserialize(serialized)
+            .map_err(DefaultSerializationError::Deserialization)
+            .map_err(EntityDeserializationError::Engine)?;
+        match deserialized {
+            DeserializableLweCompactPublicKey64 {
+                version: LweCompactPublicKey64Version::Unsupported,
+                ..
+            } => Err(EntityDeserializationError::Engine(
+                DefaultSerializationError::UnsupportedVersion,
+            )),
+            DeserializableLweCompactPublicKey64 {
+                version: LweCompactPublicKey64Version::V0,
+                inner,
+            } => Ok(LweCompactPublicKey64(inner)),
+        }
+    }
+
+    unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> LweCompactPublicKey64 {
+        self.deserialize(serialized).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntityDeserializationEngine`] for [`DefaultSerializationEngine`] that
 /// operates on 32 bits integers. It deserializes a plaintext entity.
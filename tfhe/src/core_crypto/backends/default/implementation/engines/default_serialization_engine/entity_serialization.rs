@@ -6,8 +6,8 @@ use crate::core_crypto::commons::crypto::encoding::{
 };
 use crate::core_crypto::commons::crypto::glwe::LwePrivateFunctionalPackingKeyswitchKeyList as ImplLweCircuitBoostrapPrivateFunctionalPackingKeyswitchKeys;
 use crate::core_crypto::commons::crypto::lwe::{
-    LweCiphertext as ImplLweCiphertext, LweKeyswitchKey as ImplLweKeyswitchKey,
-    LweList as ImplLweList,
+    LweCiphertext as ImplLweCiphertext, LweCompactPublicKey as ImplLweCompactPublicKey,
+    LweKeyswitchKey as ImplLweKeyswitchKey, LweList as ImplLweList,
 };
 use crate::core_crypto::commons::crypto::secret::{
     GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
@@ -23,9 +23,10 @@ use crate::core_crypto::prelude::{
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32Version,
     LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
-    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweKeyswitchKey32,
-    LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version, LwePublicKey32,
-    LwePublicKey32Version, LwePublicKey64, LwePublicKey64Version, LweSecretKey32,
+    LweCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64Version, LweCompactPublicKey32,
+    LweCompactPublicKey32Version, LweCompactPublicKey64, LweCompactPublicKey64Version,
+    LweKeyswitchKey32, LweKeyswitchKey32Version, LweKeyswitchKey64, LweKeyswitchKey64Version,
+    LwePublicKey32, LwePublicKey32Version, LwePublicKey64, LwePublicKey64Version, LweSecretKey32,
     LweSecretKey32Version, LweSecretKey64, LweSecretKey64Version, Plaintext32, Plaintext32Version,
     Plaintext64, Plaintext64Version, PlaintextVector32, PlaintextVector32Version,
     PlaintextVector64, PlaintextVector64Version,
@@ -1280,6 +1281,116 @@ impl EntitySerializationEngine<LwePublicKey64, Vec<u8>> for DefaultSerialization
     }
 }
 
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 32 bits integers. It serializes a compact LWE public key.
+impl EntitySerializationEngine<LweCompactPublicKey32, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LweCompactPublicKey32 =
+    ///     engine.generate_new_lwe_compact_public_key(&lwe_secret_key, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCompactPublicKey32,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCompactPublicKey32<'a> {
+            version: LweCompactPublicKey32Version,
+            inner: &'a ImplLweCompactPublicKey<Vec<u32>>,
+        }
+        let serializable = SerializableLweCompactPublicKey32 {
+            version: LweCompactPublicKey32Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCompactPublicKey32) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
+/// # Description:
+/// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
+/// on 64 bits integers. It serializes a compact LWE public key.
+impl EntitySerializationEngine<LweCompactPublicKey64, Vec<u8>> for DefaultSerializationEngine {
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(16);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let lwe_secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LweCompactPublicKey64 =
+    ///     engine.generate_new_lwe_compact_public_key(&lwe_secret_key, noise)?;
+    ///
+    /// let mut serialization_engine = DefaultSerializationEngine::new(())?;
+    /// let serialized = serialization_engine.serialize(&public_key)?;
+    /// let recovered = serialization_engine.deserialize(serialized.as_slice())?;
+    /// assert_eq!(public_key, recovered);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn serialize(
+        &mut self,
+        entity: &LweCompactPublicKey64,
+    ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+        #[derive(Serialize)]
+        struct SerializableLweCompactPublicKey64<'a> {
+            version: LweCompactPublicKey64Version,
+            inner: &'a ImplLweCompactPublicKey<Vec<u64>>,
+        }
+        let serializable = SerializableLweCompactPublicKey64 {
+            version: LweCompactPublicKey64Version::V0,
+            inner: &entity.0,
+        };
+        bincode::serialize(&serializable)
+            .map_err(DefaultSerializationError::Serialization)
+            .map_err(EntitySerializationError::Engine)
+    }
+
+    unsafe fn serialize_unchecked(&mut self, entity: &LweCompactPublicKey64) -> Vec<u8> {
+        self.serialize(entity).unwrap()
+    }
+}
+
 /// # Description:
 /// Implementation of [`EntitySerializationEngine`] for [`DefaultSerializationEngine`] that operates
 /// on 32 bits integers. It serializes a plaintext entity.
@@ -0,0 +1,46 @@
+use crate::core_crypto::commons::crypto::lwe::LweCompactPublicKey as ImplLweCompactPublicKey;
+use crate::core_crypto::prelude::LweDimension;
+use crate::core_crypto::specification::entities::markers::LweCompactPublicKeyKind;
+use crate::core_crypto::specification::entities::{AbstractEntity, LweCompactPublicKeyEntity};
+#[cfg(feature = "backend_default_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A structure representing a compact LWE public key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompactPublicKey32(pub(crate) ImplLweCompactPublicKey<Vec<u32>>);
+impl AbstractEntity for LweCompactPublicKey32 {
+    type Kind = LweCompactPublicKeyKind;
+}
+impl LweCompactPublicKeyEntity for LweCompactPublicKey32 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCompactPublicKey32Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
+
+/// A structure representing a compact LWE public key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompactPublicKey64(pub(crate) ImplLweCompactPublicKey<Vec<u64>>);
+impl AbstractEntity for LweCompactPublicKey64 {
+    type Kind = LweCompactPublicKeyKind;
+}
+impl LweCompactPublicKeyEntity for LweCompactPublicKey64 {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+}
+
+#[cfg(feature = "backend_default_serialization")]
+#[derive(Serialize, Deserialize)]
+pub(crate) enum LweCompactPublicKey64Version {
+    V0,
+    #[serde(other)]
+    Unsupported,
+}
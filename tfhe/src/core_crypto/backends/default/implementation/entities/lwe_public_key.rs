@@ -1,7 +1,19 @@
-use crate::core_crypto::commons::crypto::lwe::LweList as ImpLwePublicKey;
+use crate::core_crypto::backends::default::implementation::engines::ActivatedRandomGenerator;
+use crate::core_crypto::backends::default::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, Plaintext32, Plaintext64,
+};
+use crate::core_crypto::commons::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::core_crypto::commons::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweList as ImpLwePublicKey,
+};
+use crate::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+use crate::core_crypto::commons::math::random::Seed;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::prelude::{LweDimension, LwePublicKeyZeroEncryptionCount};
 use crate::core_crypto::specification::entities::markers::LwePublicKeyKind;
-use crate::core_crypto::specification::entities::{AbstractEntity, LwePublicKeyEntity};
+use crate::core_crypto::specification::entities::{
+    AbstractEntity, LweCiphertextEntity, LwePublicKeyEntity,
+};
 #[cfg(feature = "backend_default_serialization")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +34,66 @@ impl LwePublicKeyEntity for LwePublicKey32 {
     }
 }
 
+impl LwePublicKey32 {
+    /// Encrypts a plaintext with the public key, deriving the random subset of public encryptions
+    /// of zero from a seed.
+    ///
+    /// Encrypting the same plaintext twice with the same seed yields the same ciphertext, which
+    /// allows to reproduce and audit a public-key encryption.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output ciphertext and the public key have different LWE dimensions.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey32 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey32 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut first = engine.create_lwe_ciphertext_from(vec![0u32; lwe_dimension.to_lwe_size().0])?;
+    /// let mut second =
+    ///     engine.create_lwe_ciphertext_from(vec![0u32; lwe_dimension.to_lwe_size().0])?;
+    ///
+    /// public_key.encrypt_with_seed(&mut first, &plaintext, Seed(42));
+    /// public_key.encrypt_with_seed(&mut second, &plaintext, Seed(42));
+    /// #
+    /// assert_eq!(first, second);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encrypt_with_seed(&self, output: &mut LweCiphertext32, input: &Plaintext32, seed: Seed) {
+        assert_eq!(
+            self.lwe_dimension(),
+            output.lwe_dimension(),
+            "The output ciphertext and the public key have different LWE dimensions."
+        );
+        encrypt_with_seed(&self.0, &mut output.0, &input.0, seed);
+    }
+}
+
 #[cfg(feature = "backend_default_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LwePublicKey32Version {
@@ -46,6 +118,66 @@ impl LwePublicKeyEntity for LwePublicKey64 {
     }
 }
 
+impl LwePublicKey64 {
+    /// Encrypts a plaintext with the public key, deriving the random subset of public encryptions
+    /// of zero from a seed.
+    ///
+    /// Encrypting the same plaintext twice with the same seed yields the same ciphertext, which
+    /// allows to reproduce and audit a public-key encryption.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output ciphertext and the public key have different LWE dimensions.
+    ///
+    /// # Example:
+    /// ```
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// let lwe_public_key_zero_encryption_count = LwePublicKeyZeroEncryptionCount(7);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// // Unix seeder must be given a secret input.
+    /// // Here we just give it 0, which is totally unsafe.
+    /// const UNSAFE_SECRET: u128 = 0;
+    /// let mut engine = DefaultEngine::new(Box::new(UnixSeeder::new(UNSAFE_SECRET)))?;
+    /// let secret_key: LweSecretKey64 = engine.generate_new_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey64 = engine.generate_new_lwe_public_key(
+    ///     &secret_key,
+    ///     noise,
+    ///     lwe_public_key_zero_encryption_count,
+    /// )?;
+    /// let plaintext = engine.create_plaintext_from(&input)?;
+    ///
+    /// let mut first = engine.create_lwe_ciphertext_from(vec![0u64; lwe_dimension.to_lwe_size().0])?;
+    /// let mut second =
+    ///     engine.create_lwe_ciphertext_from(vec![0u64; lwe_dimension.to_lwe_size().0])?;
+    ///
+    /// public_key.encrypt_with_seed(&mut first, &plaintext, Seed(42));
+    /// public_key.encrypt_with_seed(&mut second, &plaintext, Seed(42));
+    /// #
+    /// assert_eq!(first, second);
+    ///
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encrypt_with_seed(&self, output: &mut LweCiphertext64, input: &Plaintext64, seed: Seed) {
+        assert_eq!(
+            self.lwe_dimension(),
+            output.lwe_dimension(),
+            "The output ciphertext and the public key have different LWE dimensions."
+        );
+        encrypt_with_seed(&self.0, &mut output.0, &input.0, seed);
+    }
+}
+
 #[cfg(feature = "backend_default_serialization")]
 #[derive(Serialize, Deserialize)]
 pub(crate) enum LwePublicKey64Version {
@@ -53,3 +185,13 @@ pub(crate) enum LwePublicKey64Version {
     #[serde(other)]
     Unsupported,
 }
+
+fn encrypt_with_seed<Scalar: UnsignedTorus>(
+    key: &ImpLwePublicKey<Vec<Scalar>>,
+    output: &mut ImplLweCiphertext<Vec<Scalar>>,
+    input: &ImplPlaintext<Scalar>,
+    seed: Seed,
+) {
+    let mut generator = SecretRandomGenerator::<ActivatedRandomGenerator>::new(seed);
+    key.encrypt_lwe_with_public_key(output, input, key.count(), &mut generator);
+}
@@ -44,7 +44,7 @@ impl<C: Container<Element = c64>> FourierLweBootstrapKey<C> {
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
     ) -> Self {
-        assert_eq!(polynomial_size.0 % 2, 0);
+        polynomial_size.assert_power_of_two();
         assert_eq!(
             data.container_len(),
             key_size.0 * polynomial_size.0 / 2
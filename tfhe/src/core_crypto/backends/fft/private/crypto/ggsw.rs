@@ -16,7 +16,7 @@ use crate::core_crypto::commons::utils::izip;
 use crate::core_crypto::prelude::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
 };
-use aligned_vec::CACHELINE_ALIGN;
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
 use concrete_fft::c64;
 use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
 
@@ -26,6 +26,19 @@ use core::arch::x86::*;
 use core::arch::x86_64::*;
 
 /// A GGSW ciphertext in the Fourier domain.
+///
+/// Each polynomial of the standard GGSW ciphertext is stored as the `polynomial_size / 2` complex
+/// coefficients of its negacyclic transform, computed with the FFT of [`FftView`]. A GGSW in this
+/// form can be used directly by [`external_product`] and [`cmux`].
+///
+/// # Precision
+///
+/// The coefficients are stored as `f64`, whose 53 bits of mantissa cannot hold a 64 bits torus
+/// element exactly. Converting a GGSW ciphertext back to the standard domain is exact for `u32`
+/// coefficients, while for `u64` coefficients the roundtrip error on each coefficient grows with
+/// the polynomial size. Up to a polynomial size of $2^{14}$, it is kept below $2^{14}$, i.e. the 50
+/// most significant bits are preserved. This is negligible compared to the encryption noise of the
+/// supported parameter sets.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(
     feature = "backend_fft_serialization",
@@ -73,7 +86,7 @@ impl<C: Container<Element = c64>> FourierGgswCiphertext<C> {
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
     ) -> Self {
-        assert_eq!(polynomial_size.0 % 2, 0);
+        polynomial_size.assert_power_of_two();
         assert_eq!(
             data.container_len(),
             polynomial_size.0 / 2 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
@@ -292,17 +305,11 @@ pub fn external_product_scratch<Scalar>(
     fft: FftView<'_>,
 ) -> Result<StackReq, SizeOverflow> {
     let align = CACHELINE_ALIGN;
-    let standard_scratch =
-        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, align)?;
     let fourier_scratch =
         StackReq::try_new_aligned::<c64>(glwe_size.0 * polynomial_size.0 / 2, align)?;
-    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(polynomial_size.0 / 2, align)?;
 
-    let substack3 = fft.forward_scratch()?;
-    let substack2 = substack3.try_and(fourier_scratch_single)?;
-    let substack1 = substack2.try_and(standard_scratch)?;
     let substack0 = StackReq::try_any_of([
-        substack1.try_and(standard_scratch)?,
+        decompose_and_transform_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
         fft.backward_scratch()?,
     ])?;
     substack0.try_and(fourier_scratch)
@@ -327,40 +334,82 @@ pub fn external_product<Scalar: UnsignedTorus>(
     let align = CACHELINE_ALIGN;
     let poly_size = ggsw.polynomial_size().0;
 
-    // we round the input mask and body
-    let decomposer = SignedDecomposer::<Scalar>::new(
-        ggsw.decomposition_base_log(),
-        ggsw.decomposition_level_count(),
-    );
-
     let (mut output_fft_buffer, mut substack0) =
         stack.make_aligned_uninit::<c64>(poly_size / 2 * ggsw.glwe_size().0, align);
     // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
     // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
     // it has been fully initialized for the first time.
     let output_fft_buffer = &mut *output_fft_buffer;
-    let mut is_output_uninit = true;
 
-    {
-        // ------------------------------------------------------ EXTERNAL PRODUCT IN FOURIER DOMAIN
-        // In this section, we perform the external product in the fourier domain, and accumulate
-        // the result in the output_fft_buffer variable.
-        let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
-            glwe.into_container()
-                .iter()
-                .map(|s| decomposer.closest_representable(*s)),
-            DecompositionBaseLog(decomposer.base_log),
-            DecompositionLevelCount(decomposer.level_count),
+    // SAFETY: `output_fft_buffer` is flagged as uninitialized.
+    let is_output_uninit = unsafe {
+        accumulate_external_product_in_fourier(
+            output_fft_buffer,
+            true,
+            ggsw,
+            glwe,
+            fft,
             substack0.rb_mut(),
-        );
+        )
+    };
+
+    // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+    // In this section, we bring the result from the fourier domain, back to the standard
+    // domain, and add it to the output.
+    //
+    // We iterate over the polynomials in the output.
+    if !is_output_uninit {
+        // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
+        let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+        izip!(
+            out.as_mut_view()
+                .into_polynomial_list()
+                .into_polynomial_iter(),
+            output_fft_buffer
+                .into_chunks(poly_size / 2)
+                .map(|slice| FourierPolynomialView { data: slice }),
+        )
+        .for_each(|(out, fourier)| {
+            fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+        });
+    }
+}
+
+/// Adds the external product of `ggsw` and `glwe` to `output_fft_buffer`, in the Fourier domain,
+/// and returns whether `output_fft_buffer` is still uninitialized.
+///
+/// # Safety
+///
+///  - if `is_output_uninit` is false, `output_fft_buffer` must not hold any uninitialized values.
+unsafe fn accumulate_external_product_in_fourier<Scalar: UnsignedTorus>(
+    output_fft_buffer: &mut [MaybeUninit<c64>],
+    mut is_output_uninit: bool,
+    ggsw: FourierGgswCiphertextView<'_>,
+    glwe: GlweCiphertext<&'_ [Scalar]>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) -> bool {
+    let poly_size = ggsw.polynomial_size().0;
+
+    // we round the input mask and body
+    let decomposer = SignedDecomposer::<Scalar>::new(
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+    );
 
-        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
-        ggsw.into_levels().rev().for_each(|ggsw_decomp_matrix| {
-            // We retrieve the decomposition of this level.
-            let (glwe_level, glwe_decomp_term, mut substack2) =
-                collect_next_term(&mut decomposition, &mut substack1, align);
-            let glwe_decomp_term =
-                GlweCiphertext::from_container(&*glwe_decomp_term, ggsw.polynomial_size());
+    // ------------------------------------------------------ EXTERNAL PRODUCT IN FOURIER DOMAIN
+    // In this section, we perform the external product in the fourier domain, and accumulate
+    // the result in the output_fft_buffer variable.
+    //
+    // We loop through the levels (we reverse to match the order of the decomposition.)
+    let mut ggsw_decomp_matrices = ggsw.into_levels().rev();
+    for_each_transformed_term(
+        glwe,
+        &decomposer,
+        fft,
+        stack,
+        |glwe_level, glwe_decomp_term| {
+            let ggsw_decomp_matrix = ggsw_decomp_matrices.next().unwrap();
             debug_assert_eq!(ggsw_decomp_matrix.decomposition_level(), glwe_level);
 
             // For each level we have to add the result of the vector-matrix product between the
@@ -377,26 +426,13 @@ pub fn external_product<Scalar: UnsignedTorus>(
 
             izip!(
                 ggsw_decomp_matrix.into_rows(),
-                glwe_decomp_term
-                    .into_polynomial_list()
-                    .into_polynomial_iter()
+                glwe_decomp_term.into_chunks(poly_size / 2)
             )
-            .for_each(|(ggsw_row, glwe_poly)| {
-                let (mut fourier, substack3) = substack2
-                    .rb_mut()
-                    .make_aligned_uninit::<c64>(poly_size / 2, align);
-                // We perform the forward fft transform for the glwe polynomial
-                let fourier = fft
-                    .forward_as_integer(
-                        FourierPolynomialUninitMutView { data: &mut fourier },
-                        glwe_poly,
-                        substack3,
-                    )
-                    .data;
-                // Now we loop through the polynomials of the output, and add the
-                // corresponding product of polynomials.
+            .for_each(|(ggsw_row, fourier)| {
+                // We loop through the polynomials of the output, and add the corresponding product
+                // of polynomials.
 
-                // SAFETY: see comment above definition of `output_fft_buffer`
+                // SAFETY: see the safety section of this function
                 unsafe {
                     update_with_fmadd(
                         output_fft_buffer,
@@ -410,31 +446,205 @@ pub fn external_product<Scalar: UnsignedTorus>(
                 // we initialized `output_fft_buffer, so we can set this to false
                 is_output_uninit = false;
             });
-        });
+        },
+    );
+
+    is_output_uninit
+}
+
+/// A GLWE ciphertext accumulating external products in the Fourier domain.
+///
+/// Summing the results of several calls to [`external_product`] costs one inverse FFT per
+/// polynomial and per product. The accumulator instead keeps the sum in the Fourier domain, so
+/// that the inverse FFT is only performed once, by [`FourierGlweAccumulator::finish`].
+pub struct FourierGlweAccumulator {
+    fourier: FourierPolynomialList<ABox<[c64]>>,
+    glwe_size: GlweSize,
+}
+
+/// Returns the required memory for the methods of [`FourierGlweAccumulator`].
+pub fn fourier_glwe_accumulator_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_any_of([
+        decompose_and_transform_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+        fft.backward_scratch()?,
+    ])
+}
+
+impl FourierGlweAccumulator {
+    /// Creates an accumulator holding zero, for GLWE ciphertexts of the given sizes.
+    pub fn new(glwe_size: GlweSize, polynomial_size: PolynomialSize) -> Self {
+        polynomial_size.assert_power_of_two();
+        Self {
+            fourier: FourierPolynomialList {
+                data: avec![c64::default(); glwe_size.0 * polynomial_size.0 / 2].into_boxed_slice(),
+                polynomial_size,
+            },
+            glwe_size,
+        }
     }
 
-    // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
-    // In this section, we bring the result from the fourier domain, back to the standard
-    // domain, and add it to the output.
-    //
-    // We iterate over the polynomials in the output.
-    if !is_output_uninit {
-        // SAFETY: output_fft_buffer is initialized, since `is_output_uninit` is false
-        let output_fft_buffer = &*unsafe { assume_init_mut(output_fft_buffer) };
+    /// Returns the size of the polynomials of the accumulated GLWE ciphertext.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    /// Returns the size of the accumulated GLWE ciphertext, i.e. its number of polynomials.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Adds the external product of `ggsw` and `glwe` to the accumulator.
+    pub fn add_external_product<Scalar: UnsignedTorus>(
+        &mut self,
+        ggsw: FourierGgswCiphertextView<'_>,
+        glwe: GlweCiphertext<&'_ [Scalar]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        // we check that the polynomial sizes match
+        debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+        debug_assert_eq!(ggsw.polynomial_size(), self.polynomial_size());
+        // we check that the glwe sizes match
+        debug_assert_eq!(ggsw.glwe_size(), glwe.size());
+        debug_assert_eq!(ggsw.glwe_size(), self.glwe_size());
+
+        // SAFETY: the buffer of the accumulator is always initialized.
+        unsafe {
+            accumulate_external_product_in_fourier(
+                as_mut_uninit(&mut self.fourier.data),
+                false,
+                ggsw,
+                glwe,
+                fft,
+                stack,
+            );
+        }
+    }
+
+    /// Brings the accumulated sum back to the standard domain, and adds it to `out`.
+    pub fn finish<Scalar: UnsignedTorus>(
+        self,
+        mut out: GlweCiphertext<&'_ mut [Scalar]>,
+        fft: FftView<'_>,
+        mut stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(self.polynomial_size(), out.polynomial_size());
+        debug_assert_eq!(self.glwe_size(), out.size());
+
+        let poly_size = self.polynomial_size().0;
         izip!(
             out.as_mut_view()
                 .into_polynomial_list()
                 .into_polynomial_iter(),
-            output_fft_buffer
+            self.fourier
+                .data
                 .into_chunks(poly_size / 2)
                 .map(|slice| FourierPolynomialView { data: slice }),
         )
         .for_each(|(out, fourier)| {
-            fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+            fft.add_backward_as_torus(out, fourier, stack.rb_mut());
         });
     }
 }
 
+/// Returns the required memory for [`decompose_and_transform`].
+pub fn decompose_and_transform_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch =
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, align)?;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(glwe_size.0 * polynomial_size.0 / 2, align)?;
+    standard_scratch
+        .try_and(standard_scratch)?
+        .try_and(fourier_scratch)?
+        .try_and(fft.forward_scratch()?)
+}
+
+/// Decomposes every polynomial of `glwe` with `decomposer`, and stores the forward Fourier
+/// transform of each term of the decomposition in `out`.
+///
+/// Each level of the decomposition is transformed as soon as it is computed, while it is still in
+/// cache. The `out` list holds `level_count * glwe_size` polynomials: the transforms of the
+/// polynomials of the level $j$ are stored, in order, starting at the polynomial of index
+/// $(j - 1) \cdot glwe\_size$.
+///
+/// # Panics
+///
+/// Panics if `out` does not hold `level_count * glwe_size` polynomials of the size of `glwe`.
+pub fn decompose_and_transform<Scalar: UnsignedTorus>(
+    out: FourierPolynomialList<&'_ mut [c64]>,
+    glwe: GlweCiphertext<&'_ [Scalar]>,
+    decomposer: &SignedDecomposer<Scalar>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    let poly_size = glwe.polynomial_size();
+    let level_size = glwe.size().0 * poly_size.0 / 2;
+    assert_eq!(out.polynomial_size, poly_size);
+    assert_eq!(out.data.len(), decomposer.level_count * level_size);
+    let out = out.data;
+
+    for_each_transformed_term(glwe, decomposer, fft, stack, |level, term| {
+        out[(level.0 - 1) * level_size..][..level_size].copy_from_slice(term);
+    });
+}
+
+/// Decomposes every polynomial of `glwe` with `decomposer`, and calls `f` with the level and the
+/// forward Fourier transform of each term of the decomposition, by decreasing level.
+///
+/// This is the inner loop shared by [`decompose_and_transform`] and the external product, which
+/// only needs one level of the transformed decomposition at a time.
+fn for_each_transformed_term<Scalar: UnsignedTorus>(
+    glwe: GlweCiphertext<&'_ [Scalar]>,
+    decomposer: &SignedDecomposer<Scalar>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+    mut f: impl FnMut(DecompositionLevel, &[c64]),
+) {
+    let align = CACHELINE_ALIGN;
+    let poly_size = glwe.polynomial_size().0;
+    let glwe_size = glwe.size().0;
+
+    let (mut decomposition, mut substack0) = TensorSignedDecompositionLendingIter::new(
+        glwe.into_container()
+            .iter()
+            .map(|s| decomposer.closest_representable(*s)),
+        DecompositionBaseLog(decomposer.base_log),
+        DecompositionLevelCount(decomposer.level_count),
+        stack.rb_mut(),
+    );
+
+    // The decomposition yields the levels in decreasing order.
+    for _ in 0..decomposer.level_count {
+        let (level, term, mut substack1) =
+            collect_next_term(&mut decomposition, &mut substack0, align);
+        let (mut fourier, mut substack2) = substack1
+            .rb_mut()
+            .make_aligned_uninit::<c64>(glwe_size * poly_size / 2, align);
+        izip!(
+            (&mut *fourier).into_chunks(poly_size / 2),
+            term.into_chunks(poly_size)
+        )
+        .for_each(|(fourier, standard)| {
+            fft.forward_as_integer(
+                FourierPolynomialUninitMutView { data: fourier },
+                Polynomial::from_container(standard),
+                substack2.rb_mut(),
+            );
+        });
+        // SAFETY: every polynomial of `fourier` has been initialized by forward_as_integer.
+        f(level, unsafe { assume_init_mut(&mut fourier) });
+    }
+}
+
 #[cfg_attr(__profiling, inline(never))]
 fn collect_next_term<'a, Scalar: UnsignedTorus>(
     decomposition: &mut TensorSignedDecompositionLendingIter<'_, Scalar>,
@@ -672,3 +882,285 @@ pub fn cmux<Scalar: UnsignedTorus>(
     });
     external_product(ct0, ggsw, ct1.as_view(), fft, stack);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::backends::fft::private::math::fft::Fft;
+    use crate::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor, Tensor};
+    use crate::core_crypto::commons::test_tools::new_random_generator;
+    use dyn_stack::GlobalMemBuffer;
+
+    fn test_decompose_and_transform<Scalar: UnsignedTorus>() {
+        let mut generator = new_random_generator();
+        let polynomial_size = PolynomialSize(256);
+        let glwe_size = GlweSize(3);
+        let level_count = DecompositionLevelCount(3);
+        let decomposer = SignedDecomposer::<Scalar>::new(DecompositionBaseLog(5), level_count);
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+
+        let glwe: Tensor<Vec<Scalar>> =
+            generator.random_uniform_tensor(glwe_size.0 * polynomial_size.0);
+        let glwe = GlweCiphertext::from_container(glwe.into_container(), polynomial_size);
+
+        let mut fused = vec![c64::default(); level_count.0 * glwe_size.0 * polynomial_size.0 / 2];
+        let mut mem = GlobalMemBuffer::new(
+            decompose_and_transform_scratch::<Scalar>(glwe_size, polynomial_size, fft).unwrap(),
+        );
+        decompose_and_transform(
+            FourierPolynomialList {
+                data: &mut fused,
+                polynomial_size,
+            },
+            glwe.as_view(),
+            &decomposer,
+            fft,
+            DynStack::new(&mut mem),
+        );
+
+        // Performs the decomposition and the transforms as two separate steps.
+        let mut rounded = Tensor::allocate(Scalar::ZERO, glwe.as_tensor().len());
+        decomposer.fill_tensor_with_closest_representable(&mut rounded, glwe.as_tensor());
+        let mut decomposition = decomposer.decompose_tensor(&rounded);
+        let mut mem = GlobalMemBuffer::new(fft.forward_scratch().unwrap());
+        let mut stack = DynStack::new(&mut mem);
+        let mut separate = vec![c64::default(); fused.len()];
+        while let Some(term) = decomposition.next_term() {
+            let level = term.level().0;
+            let level_size = glwe_size.0 * polynomial_size.0 / 2;
+            let level_out = &mut separate[(level - 1) * level_size..][..level_size];
+            for (fourier, standard) in izip!(
+                level_out.chunks_mut(polynomial_size.0 / 2),
+                term.as_tensor().as_slice().chunks(polynomial_size.0)
+            ) {
+                fft.forward_as_integer(
+                    FourierPolynomialUninitMutView {
+                        data: unsafe { as_mut_uninit(fourier) },
+                    },
+                    Polynomial::from_container(standard),
+                    stack.rb_mut(),
+                );
+            }
+        }
+
+        for (fused, separate) in fused.iter().zip(separate.iter()) {
+            assert!((fused - separate).norm() < 1e-9);
+        }
+    }
+
+    fn test_forward_fourier_roundtrip<Scalar: UnsignedTorus>() {
+        let mut generator = new_random_generator();
+        let glwe_size = GlweSize(2);
+        let level_count = DecompositionLevelCount(2);
+        let base_log = DecompositionBaseLog(5);
+        // The roundtrip error of the FFT grows with the polynomial size, so the bound documented on
+        // `FourierGgswCiphertext` is checked up to the largest polynomial size it covers.
+        for polynomial_size in [256, 1024, 4096, 16384].map(PolynomialSize) {
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+
+            let standard: Tensor<Vec<Scalar>> = generator.random_uniform_tensor(
+                level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0,
+            );
+            let standard = StandardGgswCiphertext::from_container(
+                standard.into_container(),
+                glwe_size,
+                polynomial_size,
+                base_log,
+            );
+
+            let mut fourier = FourierGgswCiphertext::new(
+                vec![
+                    c64::default();
+                    level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 / 2
+                ],
+                polynomial_size,
+                glwe_size,
+                base_log,
+                level_count,
+            );
+            let mut mem = GlobalMemBuffer::new(
+                fill_with_forward_fourier_scratch(fft)
+                    .unwrap()
+                    .and(fft.backward_scratch().unwrap()),
+            );
+            let mut stack = DynStack::new(&mut mem);
+            fourier.as_mut_view().fill_with_forward_fourier(
+                standard.as_view(),
+                fft,
+                stack.rb_mut(),
+            );
+
+            let mut roundtrip = vec![Scalar::ZERO; standard.as_tensor().len()];
+            for (standard_poly, fourier_poly) in izip!(
+                roundtrip.chunks_mut(polynomial_size.0),
+                fourier.data().chunks(polynomial_size.0 / 2)
+            ) {
+                fft.add_backward_as_torus(
+                    Polynomial::from_container(standard_poly),
+                    FourierPolynomialView { data: fourier_poly },
+                    stack.rb_mut(),
+                );
+            }
+
+            let bound = if Scalar::BITS == 32 {
+                Scalar::ONE
+            } else {
+                Scalar::ONE << 14
+            };
+            for (expected, actual) in izip!(standard.as_tensor().iter(), roundtrip.iter()) {
+                let distance = expected.wrapping_sub(*actual);
+                assert!(distance.min(distance.wrapping_neg()) < bound);
+            }
+        }
+    }
+
+    fn test_fourier_glwe_accumulator<Scalar: UnsignedTorus>() {
+        use crate::core_crypto::commons::crypto::encoding::{Plaintext, PlaintextList};
+        use crate::core_crypto::commons::crypto::secret::GlweSecretKey;
+        use crate::core_crypto::commons::test_tools::{
+            new_encryption_random_generator, new_secret_random_generator,
+        };
+        use crate::core_crypto::prelude::{GlweDimension, LogStandardDev};
+
+        let polynomial_size = PolynomialSize(256);
+        let glwe_dimension = GlweDimension(2);
+        let glwe_size = glwe_dimension.to_glwe_size();
+        let level_count = DecompositionLevelCount(3);
+        let base_log = DecompositionBaseLog(7);
+        let noise = LogStandardDev::from_log_standard_dev(-50.);
+        let product_count = 4;
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        let mut secret_generator = new_secret_random_generator();
+        let mut encryption_generator = new_encryption_random_generator();
+        let sk =
+            GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+
+        let mut mem = GlobalMemBuffer::new(
+            fill_with_forward_fourier_scratch(fft)
+                .unwrap()
+                .or(external_product_scratch::<Scalar>(glwe_size, polynomial_size, fft).unwrap())
+                .or(
+                    fourier_glwe_accumulator_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+                        .unwrap(),
+                ),
+        );
+        let mut stack = DynStack::new(&mut mem);
+
+        // Accumulates the products of encryptions of alternating bits with encryptions of 4 bits
+        // messages, which sum to the messages selected by the bits, modulo 16.
+        let shift = Scalar::BITS - 4;
+        let mut accumulator = FourierGlweAccumulator::new(glwe_size, polynomial_size);
+        let mut expected = vec![Scalar::ZERO; glwe_size.0 * polynomial_size.0];
+        let mut messages_sum = vec![Scalar::ZERO; polynomial_size.0];
+        for product in 0..product_count {
+            let bit = Scalar::cast_from((product % 2) as f64);
+            let mut standard = StandardGgswCiphertext::allocate(
+                Scalar::ZERO,
+                polynomial_size,
+                glwe_size,
+                level_count,
+                base_log,
+            );
+            sk.encrypt_constant_ggsw(
+                &mut standard,
+                &Plaintext(bit),
+                noise,
+                &mut encryption_generator,
+            );
+            let mut ggsw = FourierGgswCiphertext::new(
+                vec![
+                    c64::default();
+                    level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0 / 2
+                ],
+                polynomial_size,
+                glwe_size,
+                base_log,
+                level_count,
+            );
+            ggsw.as_mut_view()
+                .fill_with_forward_fourier(standard.as_view(), fft, stack.rb_mut());
+
+            let messages: Vec<Scalar> = (0..polynomial_size.0)
+                .map(|i| Scalar::cast_from(((i + 3 * product) % 16) as f64))
+                .collect();
+            for (sum, message) in izip!(messages_sum.iter_mut(), messages.iter()) {
+                *sum = sum.wrapping_add(bit * *message);
+            }
+            let plaintexts = PlaintextList::from_container(
+                messages.iter().map(|m| *m << shift).collect::<Vec<_>>(),
+            );
+            let mut glwe = GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, glwe_size);
+            sk.encrypt_glwe(&mut glwe, &plaintexts, noise, &mut encryption_generator);
+
+            external_product(
+                GlweCiphertext::from_container(expected.as_mut_slice(), polynomial_size),
+                ggsw.as_view(),
+                glwe.as_view(),
+                fft,
+                stack.rb_mut(),
+            );
+            accumulator.add_external_product(ggsw.as_view(), glwe.as_view(), fft, stack.rb_mut());
+        }
+
+        let mut actual = vec![Scalar::ZERO; glwe_size.0 * polynomial_size.0];
+        accumulator.finish(
+            GlweCiphertext::from_container(actual.as_mut_slice(), polynomial_size),
+            fft,
+            stack.rb_mut(),
+        );
+
+        // The accumulated sum decrypts to the selected messages, as the sum of the external
+        // products computed one by one does.
+        let messages_sum: Vec<Scalar> = messages_sum
+            .iter()
+            .map(|m| *m % Scalar::cast_from(16.))
+            .collect();
+        for output in [expected, actual] {
+            let mut decrypted =
+                PlaintextList::from_container(vec![Scalar::ZERO; polynomial_size.0]);
+            sk.decrypt_glwe(
+                &mut decrypted,
+                &GlweCiphertext::from_container(output, polynomial_size),
+            );
+            let decoded: Vec<Scalar> = decrypted
+                .as_tensor()
+                .iter()
+                .map(|p| p.wrapping_add(Scalar::ONE << (shift - 1)) >> shift)
+                .collect();
+            assert_eq!(decoded, messages_sum);
+        }
+    }
+
+    #[test]
+    fn test_fourier_glwe_accumulator_u32() {
+        test_fourier_glwe_accumulator::<u32>();
+    }
+
+    #[test]
+    fn test_fourier_glwe_accumulator_u64() {
+        test_fourier_glwe_accumulator::<u64>();
+    }
+
+    #[test]
+    fn test_forward_fourier_roundtrip_u32() {
+        test_forward_fourier_roundtrip::<u32>();
+    }
+
+    #[test]
+    fn test_forward_fourier_roundtrip_u64() {
+        test_forward_fourier_roundtrip::<u64>();
+    }
+
+    #[test]
+    fn test_decompose_and_transform_u32() {
+        test_decompose_and_transform::<u32>();
+    }
+
+    #[test]
+    fn test_decompose_and_transform_u64() {
+        test_decompose_and_transform::<u64>();
+    }
+}
@@ -501,7 +501,7 @@ impl<C: Container<Element = c64>> FourierGgswCiphertextList<C> {
         decomposition_base_log: DecompositionBaseLog,
         decomposition_level_count: DecompositionLevelCount,
     ) -> Self {
-        assert_eq!(polynomial_size.0 % 2, 0);
+        polynomial_size.assert_power_of_two();
         assert_eq!(
             data.container_len(),
             count * polynomial_size.0 / 2 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
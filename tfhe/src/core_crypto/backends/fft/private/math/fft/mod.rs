@@ -148,7 +148,12 @@ fn id<From: 'static, To: 'static>(slice: &[From]) -> &[To] {
 
 impl Fft {
     /// Real polynomial of size `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two.
     pub fn new(size: PolynomialSize) -> Self {
+        size.assert_power_of_two();
         let global_plans = plans();
 
         let n = size.0;
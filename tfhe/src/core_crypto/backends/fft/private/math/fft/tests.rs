@@ -65,25 +65,6 @@ fn test_roundtrip<Scalar: UnsignedTorus>() {
 }
 
 fn test_product<Scalar: UnsignedTorus>() {
-    fn convolution_naive<Scalar: UnsignedTorus>(
-        out: &mut [Scalar],
-        lhs: &[Scalar],
-        rhs: &[Scalar],
-    ) {
-        assert_eq!(out.len(), lhs.len());
-        assert_eq!(out.len(), rhs.len());
-        let n = out.len();
-        let mut full_prod = vec![Scalar::ZERO; 2 * n];
-        for i in 0..n {
-            for j in 0..n {
-                full_prod[i + j] = full_prod[i + j].wrapping_add(lhs[i].wrapping_mul(rhs[j]));
-            }
-        }
-        for i in 0..n {
-            out[i] = full_prod[i].wrapping_sub(full_prod[i + n]);
-        }
-    }
-
     let mut generator = new_random_generator();
     for i in 5..=14 {
         for _ in 0..100 {
@@ -99,7 +80,7 @@ fn test_product<Scalar: UnsignedTorus>() {
 
             let mut convolution_from_fft =
                 Polynomial::from_container(avec![Scalar::ZERO; size].into_boxed_slice());
-            let mut convolution_from_naive =
+            let mut convolution_from_reference =
                 Polynomial::from_container(avec![Scalar::ZERO; size].into_boxed_slice());
 
             let mut fourier0 = FourierPolynomial {
@@ -154,14 +135,14 @@ fn test_product<Scalar: UnsignedTorus>() {
                 fourier0.as_view(),
                 stack.rb_mut(),
             );
-            convolution_naive(
-                convolution_from_naive.tensor.as_mut_container(),
-                poly0.tensor.as_container(),
-                poly1.tensor.as_container(),
-            );
+            // The schoolbook product is exact (modulo the wrapping of the integer type), and is
+            // used as the ground truth for the FFT one.
+            convolution_from_reference
+                .as_mut_view()
+                .fill_with_wrapping_mul(&poly0.as_view(), &poly1.as_view());
 
             for (expected, actual) in izip!(
-                convolution_from_naive.tensor.as_container().iter(),
+                convolution_from_reference.tensor.as_container().iter(),
                 convolution_from_fft.tensor.as_container().iter()
             ) {
                 assert!(abs_diff(*expected, *actual) < (Scalar::ONE << (Scalar::BITS - 5)));
@@ -170,6 +151,22 @@ fn test_product<Scalar: UnsignedTorus>() {
     }
 }
 
+#[test]
+fn test_fft_accepts_power_of_two_sizes() {
+    for log2 in 1..=14 {
+        let size = PolynomialSize::new_power_of_two(log2);
+        size.assert_power_of_two();
+        let fft = Fft::new(size);
+        assert_eq!(fft.as_view().polynomial_size(), size);
+    }
+}
+
+#[test]
+#[should_panic(expected = "The polynomial size 10 is not a power of two")]
+fn test_fft_rejects_other_sizes() {
+    let _ = Fft::new(PolynomialSize(10));
+}
+
 #[test]
 fn test_product_u32() {
     test_product::<u32>();
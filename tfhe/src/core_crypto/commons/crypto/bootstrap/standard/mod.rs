@@ -85,6 +85,96 @@ impl<Scalar> StandardBootstrapKey<Vec<Scalar>> {
             poly_size,
         }
     }
+
+    /// Generates a new bootstrap key from the input parameters, using all the available threads.
+    ///
+    /// Each GGSW ciphertext of the key is encrypted with its own generator, forked from
+    /// `generator`, so that the resulting key is the same as the one produced by
+    /// [`fill_with_new_key`](StandardBootstrapKey::fill_with_new_key) from an identical generator.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "__commons_parallel" feature
+    /// gate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::bootstrap::StandardBootstrapKey;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(9));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+    /// let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+    ///
+    /// let mut encryption_generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+    ///     Seed(0),
+    ///     &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(1)),
+    /// );
+    /// let par_bsk: StandardBootstrapKey<Vec<u32>> = StandardBootstrapKey::par_generate(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut encryption_generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+    ///     Seed(0),
+    ///     &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(1)),
+    /// );
+    /// let mut bsk = StandardBootstrapKey::allocate(
+    ///     0u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     lwe_dim,
+    /// );
+    /// bsk.fill_with_new_key(&lwe_sk, &glwe_sk, noise, &mut encryption_generator);
+    ///
+    /// assert_eq!(par_bsk, bsk);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_generate<LweCont, RlweCont, Gen>(
+        lwe_secret_key: &LweSecretKey<BinaryKeyKind, LweCont>,
+        glwe_secret_key: &GlweSecretKey<BinaryKeyKind, RlweCont>,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        noise_parameters: impl DispersionParameter + Sync + Send,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) -> StandardBootstrapKey<Vec<Scalar>>
+    where
+        LweSecretKey<BinaryKeyKind, LweCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, RlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+        RlweCont: Sync,
+        Gen: ParallelByteRandomGenerator,
+    {
+        let mut bsk = StandardBootstrapKey::allocate(
+            Scalar::ZERO,
+            glwe_secret_key.key_size().to_glwe_size(),
+            glwe_secret_key.polynomial_size(),
+            decomp_level,
+            decomp_base_log,
+            lwe_secret_key.key_size(),
+        );
+        bsk.par_fill_with_new_key(lwe_secret_key, glwe_secret_key, noise_parameters, generator);
+        bsk
+    }
 }
 
 impl<Cont> StandardBootstrapKey<Cont> {
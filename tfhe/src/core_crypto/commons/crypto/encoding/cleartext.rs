@@ -11,6 +11,28 @@ use serde::{Deserialize, Serialize};
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Cleartext<T: Numeric>(pub T);
 
+#[cfg(feature = "subtle")]
+impl<T: Numeric + subtle::ConstantTimeEq> Cleartext<T> {
+    /// Compares two cleartexts in constant time.
+    ///
+    /// Contrary to the derived `PartialEq` implementation, the running time does not depend on
+    /// the position of the first differing bit, which avoids leaking information when branching
+    /// on decrypted values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::Cleartext;
+    /// let cleartext = Cleartext(42_u64);
+    /// for other in [Cleartext(42_u64), Cleartext(43), Cleartext(42 | 1 << 63)] {
+    ///     assert_eq!(bool::from(cleartext.ct_eq(&other)), cleartext == other);
+    /// }
+    /// ```
+    pub fn ct_eq(&self, other: &Cleartext<T>) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 /// A list of clear, non-encoded, values.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
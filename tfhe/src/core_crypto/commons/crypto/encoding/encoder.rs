@@ -0,0 +1,161 @@
+use super::Plaintext;
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// An encoder scaling integer messages by a fixed factor `delta`.
+///
+/// A message `m` is encoded as the plaintext `m * delta` (modulo $2^{64}$), and a plaintext is
+/// decoded by rounding it to the closest multiple of `delta`.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Encoder {
+    delta: u64,
+}
+
+impl Encoder {
+    /// Creates a new encoder from a scaling factor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::Encoder;
+    /// let encoder = Encoder::new(1 << 60);
+    /// assert_eq!(encoder.delta(), 1 << 60);
+    /// ```
+    pub fn new(delta: u64) -> Encoder {
+        assert_ne!(delta, 0, "The scaling factor of an encoder cannot be 0");
+        Encoder { delta }
+    }
+
+    /// Returns the scaling factor of the encoder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::Encoder;
+    /// let encoder = Encoder::new(1 << 60);
+    /// assert_eq!(encoder.delta(), 1 << 60);
+    /// ```
+    pub fn delta(&self) -> u64 {
+        self.delta
+    }
+
+    /// Encodes a message into a plaintext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Encoder, Plaintext};
+    /// let encoder = Encoder::new(1 << 60);
+    /// assert_eq!(encoder.encode(3), Plaintext(3 << 60));
+    /// ```
+    pub fn encode(&self, message: u64) -> Plaintext<u64> {
+        Plaintext(message.wrapping_mul(self.delta))
+    }
+
+    /// Decodes a plaintext into a message, rounding to the closest multiple of the scaling factor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Encoder, Plaintext};
+    /// let encoder = Encoder::new(1 << 60);
+    /// assert_eq!(encoder.decode(Plaintext((3 << 60) + 1000)), 3);
+    /// assert_eq!(encoder.decode(Plaintext((3 << 60) - 1000)), 3);
+    /// ```
+    pub fn decode(&self, plaintext: Plaintext<u64>) -> u64 {
+        plaintext.0.wrapping_add(self.delta / 2) / self.delta
+    }
+}
+
+/// Returns the scaling factor placing a message and its carry under the padding bit.
+fn message_delta<Scalar>(message_modulus: u64, carry_modulus: u64) -> Scalar
+where
+    Scalar: UnsignedInteger + CastFrom<u64>,
+{
+    let total_modulus = message_modulus * carry_modulus;
+    assert!(
+        total_modulus.is_power_of_two() && total_modulus as u128 <= 1 << (Scalar::BITS - 2),
+        "The product of the message and carry moduli must be a power of two smaller than \
+        2^{}, got {total_modulus}",
+        Scalar::BITS - 2
+    );
+    (Scalar::ONE << (Scalar::BITS - 1)) / Scalar::cast_from(total_modulus)
+}
+
+/// Encodes a message into the most significant bits of a plaintext.
+///
+/// The plaintext is split as follows, from the most significant bit down: one padding bit, the
+/// carry bits, the message bits, and the remaining bits left for the noise. The message is
+/// reduced modulo `message_modulus` before being encoded.
+///
+/// # Panics
+///
+/// Panics if `message_modulus * carry_modulus` is not a power of two, or leaves less than one
+/// bit for the noise.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::crypto::encoding::{encode_message, Plaintext};
+/// // One padding bit, two bits of carry and two bits of message.
+/// let plaintext: Plaintext<u64> = encode_message(3, 4, 4);
+/// assert_eq!(plaintext, Plaintext(3 << 59));
+/// ```
+pub fn encode_message<Scalar>(
+    msg: u64,
+    message_modulus: u64,
+    carry_modulus: u64,
+) -> Plaintext<Scalar>
+where
+    Scalar: UnsignedInteger + CastFrom<u64>,
+{
+    let delta: Scalar = message_delta(message_modulus, carry_modulus);
+    Plaintext(Scalar::cast_from(msg % message_modulus).wrapping_mul(delta))
+}
+
+/// Decodes a plaintext produced by [`encode_message`] into a message and its carry.
+///
+/// The plaintext is rounded to the closest multiple of the scaling factor, and the result is
+/// returned modulo `message_modulus * carry_modulus`, the carry being kept in the upper bits.
+///
+/// # Panics
+///
+/// Panics if `message_modulus * carry_modulus` is not a power of two, or leaves less than one
+/// bit for the noise.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::crypto::encoding::{
+///     decode_plaintext, encode_message, Plaintext,
+/// };
+/// // Every message of a 2 bits message, 2 bits carry configuration survives a small noise.
+/// for msg in 0..4 {
+///     let Plaintext(encoded): Plaintext<u64> = encode_message(msg, 4, 4);
+///     assert_eq!(
+///         decode_plaintext(Plaintext(encoded.wrapping_add(1 << 50)), 4, 4),
+///         msg
+///     );
+///     assert_eq!(
+///         decode_plaintext(Plaintext(encoded.wrapping_sub(1 << 50)), 4, 4),
+///         msg
+///     );
+/// }
+/// // The carry is decoded along with the message.
+/// assert_eq!(decode_plaintext(Plaintext(13u64 << 59), 4, 4), 13);
+/// ```
+pub fn decode_plaintext<Scalar>(
+    plaintext: Plaintext<Scalar>,
+    message_modulus: u64,
+    carry_modulus: u64,
+) -> u64
+where
+    Scalar: UnsignedInteger + CastFrom<u64> + CastInto<u64>,
+{
+    let delta: Scalar = message_delta(message_modulus, carry_modulus);
+    let rounded = plaintext.0.wrapping_add(delta >> 1) / delta;
+    let decoded: u64 = rounded.cast_into();
+    decoded % (message_modulus * carry_modulus)
+}
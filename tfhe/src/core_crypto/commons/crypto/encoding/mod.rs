@@ -3,5 +3,8 @@
 mod cleartext;
 pub use cleartext::*;
 
+mod encoder;
+pub use encoder::*;
+
 mod plaintext;
 pub use plaintext::*;
@@ -1,3 +1,4 @@
+use super::Encoder;
 use crate::core_crypto::commons::math::polynomial::Polynomial;
 use crate::core_crypto::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
@@ -43,6 +44,29 @@ where
     }
 }
 
+impl PlaintextList<Vec<u64>> {
+    /// Creates a new list of plaintexts by encoding a slice of messages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::*;
+    /// use tfhe::core_crypto::prelude::PlaintextCount;
+    /// let encoder = Encoder::new(1 << 60);
+    /// let messages = vec![0, 1, 2, 3, 15];
+    /// let plain_list = PlaintextList::encode_from(&messages, &encoder);
+    /// assert_eq!(plain_list.count(), PlaintextCount(5));
+    /// let decoded: Vec<u64> = plain_list
+    ///     .plaintext_iter()
+    ///     .map(|p| encoder.decode(*p))
+    ///     .collect();
+    /// assert_eq!(decoded, messages);
+    /// ```
+    pub fn encode_from(messages: &[u64], encoder: &Encoder) -> PlaintextList<Vec<u64>> {
+        PlaintextList::from_container(messages.iter().map(|m| encoder.encode(*m).0).collect())
+    }
+}
+
 impl<Cont> PlaintextList<Cont> {
     /// Creates a plaintext list from a container of values.
     ///
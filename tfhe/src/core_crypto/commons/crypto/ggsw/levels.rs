@@ -55,6 +55,26 @@ impl<Cont> GgswLevelMatrix<Cont> {
         }
     }
 
+    /// Consumes the level matrix and returns its container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::ggsw::GgswLevelMatrix;
+    /// use tfhe::core_crypto::commons::math::decomposition::DecompositionLevel;
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let level_matrix = GgswLevelMatrix::from_container(
+    ///     vec![0 as u8; 10 * 7 * 7],
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevel(1),
+    /// );
+    /// assert_eq!(level_matrix.into_container(), vec![0 as u8; 10 * 7 * 7]);
+    /// ```
+    pub fn into_container(self) -> Cont {
+        self.tensor.into_container()
+    }
+
     /// Returns the size of the GLWE ciphertexts composing the GGSW level matrix.
     ///
     /// This is also the number of columns of the matrix (assuming it is a matrix of
@@ -144,8 +164,10 @@ impl<Cont> GgswLevelMatrix<Cont> {
     {
         self.as_tensor()
             .subtensor_iter(self.poly_size.0 * self.glwe_size.0)
-            .map(move |tens| {
+            .enumerate()
+            .map(move |(index, tens)| {
                 GgswLevelRow::from_container(tens.into_container(), self.poly_size, self.level)
+                    .with_row_index(index)
             })
     }
 
@@ -181,7 +203,59 @@ impl<Cont> GgswLevelMatrix<Cont> {
         let level = self.level;
         self.as_mut_tensor()
             .subtensor_iter_mut(chunks_size)
-            .map(move |tens| GgswLevelRow::from_container(tens.into_container(), poly_size, level))
+            .enumerate()
+            .map(move |(index, tens)| {
+                GgswLevelRow::from_container(tens.into_container(), poly_size, level)
+                    .with_row_index(index)
+            })
+    }
+
+    /// Returns a parallel iterator over the borrowed rows of the matrix.
+    ///
+    /// The rows are yielded in the same order as [`row_iter`](`GgswLevelMatrix::row_iter`).
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "__commons_parallel" feature
+    /// gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rayon::iter::ParallelIterator;
+    /// use tfhe::core_crypto::commons::crypto::ggsw::GgswLevelMatrix;
+    /// use tfhe::core_crypto::commons::math::decomposition::DecompositionLevel;
+    /// use tfhe::core_crypto::commons::math::tensor::AsRefTensor;
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let level_matrix = GgswLevelMatrix::from_container(
+    ///     vec![9 as u8; 10 * 7 * 7],
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevel(1),
+    /// );
+    /// level_matrix.par_row_iter().for_each(|row| {
+    ///     assert!(row.as_tensor().iter().all(|a| *a == 9));
+    /// });
+    /// assert_eq!(level_matrix.par_row_iter().count(), 7);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_row_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = GgswLevelRow<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        let chunks_size = self.poly_size.0 * self.glwe_size.0;
+        let poly_size = self.poly_size;
+        let level = self.level;
+        self.as_tensor()
+            .par_subtensor_iter(chunks_size)
+            .enumerate()
+            .map(move |(index, tens)| {
+                GgswLevelRow::from_container(tens.into_container(), poly_size, level)
+                    .with_row_index(index)
+            })
     }
 
     /// Returns a parallel iterator over the mutably borrowed rows of the matrix.
@@ -222,7 +296,11 @@ impl<Cont> GgswLevelMatrix<Cont> {
         let level = self.level;
         self.as_mut_tensor()
             .par_subtensor_iter_mut(chunks_size)
-            .map(move |tens| GgswLevelRow::from_container(tens.into_container(), poly_size, level))
+            .enumerate()
+            .map(move |(index, tens)| {
+                GgswLevelRow::from_container(tens.into_container(), poly_size, level)
+                    .with_row_index(index)
+            })
     }
 }
 
@@ -231,6 +309,7 @@ pub struct GgswLevelRow<Cont> {
     tensor: Tensor<Cont>,
     poly_size: PolynomialSize,
     level: DecompositionLevel,
+    row_index: Option<usize>,
 }
 
 tensor_traits!(GgswLevelRow);
@@ -263,9 +342,15 @@ impl<Cont> GgswLevelRow<Cont> {
             tensor,
             poly_size,
             level,
+            row_index: None,
         }
     }
 
+    fn with_row_index(mut self, row_index: usize) -> Self {
+        self.row_index = Some(row_index);
+        self
+    }
+
     /// Returns the size of the glwe ciphertext composing this level row.
     ///
     /// # Example
@@ -308,6 +393,37 @@ impl<Cont> GgswLevelRow<Cont> {
         self.level
     }
 
+    /// Returns the position of this row in its level matrix.
+    ///
+    /// The index is only known for rows yielded by the iterators of
+    /// [`GgswLevelMatrix`](`super::GgswLevelMatrix`), and is `None` for rows created from a
+    /// container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::ggsw::{GgswLevelMatrix, GgswLevelRow};
+    /// use tfhe::core_crypto::commons::math::decomposition::DecompositionLevel;
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let level_row = GgswLevelRow::from_container(
+    ///     vec![0 as u8; 10 * 7],
+    ///     PolynomialSize(10),
+    ///     DecompositionLevel(1),
+    /// );
+    /// assert_eq!(level_row.row_index(), None);
+    /// let level_matrix = GgswLevelMatrix::from_container(
+    ///     vec![0 as u8; 10 * 7 * 7],
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevel(1),
+    /// );
+    /// let row = level_matrix.row_iter().nth(3).unwrap();
+    /// assert_eq!(row.row_index(), Some(3));
+    /// ```
+    pub fn row_index(&self) -> Option<usize> {
+        self.row_index
+    }
+
     /// Returns the size of the polynomials used in the row.
     ///
     /// # Example
@@ -1,4 +1,36 @@
 //! GGSW encryption scheme.
+//!
+//! A GGSW ciphertext is represented by a [`StandardGgswCiphertext`], which owns all of its
+//! decomposition levels. Its container is made of `level_count` consecutive
+//! [`GgswLevelMatrix`], each of them containing `glwe_size` [`GgswLevelRow`] of `glwe_size`
+//! polynomials, i.e. `glwe_size^2 * poly_size` elements per level.
+//!
+//! # Example
+//!
+//! ```
+//! use tfhe::core_crypto::commons::crypto::ggsw::StandardGgswCiphertext;
+//! use tfhe::core_crypto::commons::math::decomposition::DecompositionLevel;
+//! use tfhe::core_crypto::commons::math::tensor::AsRefTensor;
+//! use tfhe::core_crypto::prelude::{
+//!     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+//! };
+//!
+//! let ggsw = StandardGgswCiphertext::from_container(
+//!     (0..3 * 2 * 2 * 4).map(|i| i as u8).collect::<Vec<_>>(),
+//!     GlweSize(2),
+//!     PolynomialSize(4),
+//!     DecompositionBaseLog(4),
+//! );
+//! assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+//! for (index, level_matrix) in ggsw.level_matrix_iter().enumerate() {
+//!     assert_eq!(
+//!         level_matrix.decomposition_level(),
+//!         DecompositionLevel(index + 1)
+//!     );
+//!     assert_eq!(level_matrix.as_tensor().len(), 2 * 2 * 4);
+//!     assert_eq!(*level_matrix.as_tensor().first(), (index * 2 * 2 * 4) as u8);
+//! }
+//! ```
 
 mod levels;
 mod seeded_levels;
@@ -1,8 +1,9 @@
 use crate::core_crypto::commons::crypto::encoding::Plaintext;
 use crate::core_crypto::commons::math::tensor::Container;
 
-use crate::core_crypto::commons::crypto::glwe::GlweList;
-use crate::core_crypto::commons::math::decomposition::DecompositionLevel;
+use crate::core_crypto::commons::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::core_crypto::commons::math::polynomial::Polynomial;
 use crate::core_crypto::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
@@ -18,6 +19,66 @@ use crate::core_crypto::prelude::{
 use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur when assembling a GGSW ciphertext from GLWE ciphertexts or level
+/// matrices which do not have the expected shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// No GLWE ciphertext was provided.
+    Empty,
+    /// The number of GLWE ciphertexts is not `level_count * glwe_size`.
+    CiphertextCount { expected: usize, got: usize },
+    /// A GLWE ciphertext does not have the same GLWE size as the first one.
+    GlweSize { expected: GlweSize, got: GlweSize },
+    /// A GLWE ciphertext does not have the same polynomial size as the first one.
+    PolynomialSize {
+        expected: PolynomialSize,
+        got: PolynomialSize,
+    },
+    /// A level matrix is not found at the position of its decomposition level.
+    DecompositionLevel {
+        expected: DecompositionLevel,
+        got: DecompositionLevel,
+    },
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShapeError::Empty => {
+                write!(f, "No GLWE ciphertext was provided.")
+            }
+            ShapeError::CiphertextCount { expected, got } => {
+                write!(f, "Expected {expected} GLWE ciphertexts, got {got}.")
+            }
+            ShapeError::GlweSize { expected, got } => {
+                write!(
+                    f,
+                    "Expected GLWE ciphertexts of size {}, got {}.",
+                    expected.0, got.0
+                )
+            }
+            ShapeError::PolynomialSize { expected, got } => {
+                write!(
+                    f,
+                    "Expected GLWE ciphertexts of polynomial size {}, got {}.",
+                    expected.0, got.0
+                )
+            }
+            ShapeError::DecompositionLevel { expected, got } => {
+                write!(
+                    f,
+                    "Expected a level matrix of decomposition level {}, got {}.",
+                    expected.0, got.0
+                )
+            }
+        }
+    }
+}
+
+impl Error for ShapeError {}
 
 /// A GGSW ciphertext.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -78,6 +139,235 @@ impl<Scalar> StandardGgswCiphertext<Vec<Scalar>> {
     }
 }
 
+impl<Scalar> StandardGgswCiphertext<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Assembles a GGSW ciphertext from GLWE ciphertexts.
+    ///
+    /// The ciphertexts are expected in the order in which they are stored in the GGSW: the rows
+    /// of the first level matrix come first, then the rows of the second one, and so on. There
+    /// must be exactly `level_count * glwe_size` ciphertexts, all sharing the same GLWE size and
+    /// polynomial size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::ggsw::{ShapeError, StandardGgswCiphertext};
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    ///
+    /// let ciphertexts: Vec<_> = (0..6)
+    ///     .map(|i| GlweCiphertext::allocate(i as u8, PolynomialSize(10), GlweSize(3)))
+    ///     .collect();
+    /// let ggsw = StandardGgswCiphertext::from_glwe_ciphertexts(
+    ///     ciphertexts,
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(2),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(3));
+    /// assert_eq!(ggsw.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(2));
+    ///
+    /// let ciphertexts = vec![GlweCiphertext::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(3),
+    /// )];
+    /// let error = StandardGgswCiphertext::from_glwe_ciphertexts(
+    ///     ciphertexts,
+    ///     DecompositionBaseLog(4),
+    ///     DecompositionLevelCount(2),
+    /// )
+    /// .unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     ShapeError::CiphertextCount {
+    ///         expected: 6,
+    ///         got: 1
+    ///     }
+    /// );
+    /// ```
+    pub fn from_glwe_ciphertexts(
+        ciphertexts: Vec<GlweCiphertext<Vec<Scalar>>>,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level: DecompositionLevelCount,
+    ) -> Result<Self, ShapeError> {
+        let (rlwe_size, poly_size) = match ciphertexts.first() {
+            Some(first) => (first.size(), first.polynomial_size()),
+            None => return Err(ShapeError::Empty),
+        };
+        if ciphertexts.len() != decomp_level.0 * rlwe_size.0 {
+            return Err(ShapeError::CiphertextCount {
+                expected: decomp_level.0 * rlwe_size.0,
+                got: ciphertexts.len(),
+            });
+        }
+        let mut container = Vec::with_capacity(ciphertexts.len() * rlwe_size.0 * poly_size.0);
+        for ciphertext in ciphertexts.iter() {
+            if ciphertext.size() != rlwe_size {
+                return Err(ShapeError::GlweSize {
+                    expected: rlwe_size,
+                    got: ciphertext.size(),
+                });
+            }
+            if ciphertext.polynomial_size() != poly_size {
+                return Err(ShapeError::PolynomialSize {
+                    expected: poly_size,
+                    got: ciphertext.polynomial_size(),
+                });
+            }
+            container.extend_from_slice(ciphertext.as_tensor().as_slice());
+        }
+        Ok(StandardGgswCiphertext::from_container(
+            container,
+            rlwe_size,
+            poly_size,
+            decomp_base_log,
+        ))
+    }
+
+    /// Assembles a GGSW ciphertext from owned level matrices.
+    ///
+    /// The matrices are expected in the order of their decomposition levels, starting from level
+    /// 1, and must all share the same GLWE size and polynomial size. This is the inverse of
+    /// [`into_level_matrices`](`StandardGgswCiphertext::into_level_matrices`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::ggsw::{
+    ///     GgswLevelMatrix, ShapeError, StandardGgswCiphertext,
+    /// };
+    /// use tfhe::core_crypto::commons::math::decomposition::DecompositionLevel;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    ///
+    /// let matrices: Vec<_> = (1..=3)
+    ///     .map(|level| {
+    ///         GgswLevelMatrix::from_container(
+    ///             vec![level as u8; 10 * 2 * 2],
+    ///             PolynomialSize(10),
+    ///             GlweSize(2),
+    ///             DecompositionLevel(level),
+    ///         )
+    ///     })
+    ///     .collect();
+    /// let ggsw =
+    ///     StandardGgswCiphertext::from_level_matrices(matrices, DecompositionBaseLog(4)).unwrap();
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(2));
+    /// assert_eq!(ggsw.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    ///
+    /// let matrices = vec![GgswLevelMatrix::from_container(
+    ///     vec![0 as u8; 10 * 2 * 2],
+    ///     PolynomialSize(10),
+    ///     GlweSize(2),
+    ///     DecompositionLevel(2),
+    /// )];
+    /// let error =
+    ///     StandardGgswCiphertext::from_level_matrices(matrices, DecompositionBaseLog(4)).unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     ShapeError::DecompositionLevel {
+    ///         expected: DecompositionLevel(1),
+    ///         got: DecompositionLevel(2)
+    ///     }
+    /// );
+    /// ```
+    pub fn from_level_matrices(
+        matrices: Vec<GgswLevelMatrix<Vec<Scalar>>>,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Result<Self, ShapeError> {
+        let (rlwe_size, poly_size) = match matrices.first() {
+            Some(first) => (first.glwe_size(), first.polynomial_size()),
+            None => return Err(ShapeError::Empty),
+        };
+        let mut container =
+            Vec::with_capacity(matrices.len() * rlwe_size.0 * rlwe_size.0 * poly_size.0);
+        for (index, matrix) in matrices.into_iter().enumerate() {
+            if matrix.decomposition_level() != DecompositionLevel(index + 1) {
+                return Err(ShapeError::DecompositionLevel {
+                    expected: DecompositionLevel(index + 1),
+                    got: matrix.decomposition_level(),
+                });
+            }
+            if matrix.glwe_size() != rlwe_size {
+                return Err(ShapeError::GlweSize {
+                    expected: rlwe_size,
+                    got: matrix.glwe_size(),
+                });
+            }
+            if matrix.polynomial_size() != poly_size {
+                return Err(ShapeError::PolynomialSize {
+                    expected: poly_size,
+                    got: matrix.polynomial_size(),
+                });
+            }
+            container.extend(matrix.into_container());
+        }
+        Ok(StandardGgswCiphertext::from_container(
+            container,
+            rlwe_size,
+            poly_size,
+            decomp_base_log,
+        ))
+    }
+
+    /// Consumes the GGSW ciphertext and returns its level matrices, as owned values.
+    ///
+    /// The matrices are returned in the order of their decomposition levels, starting from
+    /// level 1. They can be put back together with
+    /// [`from_level_matrices`](`StandardGgswCiphertext::from_level_matrices`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::ggsw::StandardGgswCiphertext;
+    /// use tfhe::core_crypto::commons::math::decomposition::DecompositionLevel;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    ///
+    /// let ggsw = StandardGgswCiphertext::allocate(
+    ///     9 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// let matrices = ggsw.into_level_matrices();
+    /// assert_eq!(matrices.len(), 3);
+    /// for (index, matrix) in matrices.iter().enumerate() {
+    ///     assert_eq!(matrix.decomposition_level(), DecompositionLevel(index + 1));
+    ///     assert_eq!(matrix.glwe_size(), GlweSize(2));
+    ///     assert_eq!(matrix.polynomial_size(), PolynomialSize(10));
+    /// }
+    /// ```
+    pub fn into_level_matrices(self) -> Vec<GgswLevelMatrix<Vec<Scalar>>> {
+        let chunks_size = self.poly_size.0 * self.rlwe_size.0 * self.rlwe_size.0;
+        let poly_size = self.poly_size;
+        let rlwe_size = self.rlwe_size;
+        self.tensor
+            .into_container()
+            .chunks_exact(chunks_size)
+            .enumerate()
+            .map(|(index, chunk)| {
+                GgswLevelMatrix::from_container(
+                    chunk.to_vec(),
+                    poly_size,
+                    rlwe_size,
+                    DecompositionLevel(index + 1),
+                )
+            })
+            .collect()
+    }
+}
+
 impl<Scalar> StandardGgswCiphertext<Vec<Scalar>>
 where
     Scalar: UnsignedTorus,
@@ -503,6 +793,169 @@ impl<Cont> StandardGgswCiphertext<Cont> {
             })
     }
 
+    /// Computes the external product of the GGSW ciphertext with a GLWE ciphertext, and adds
+    /// the result to the `output` GLWE ciphertext.
+    ///
+    /// The GLWE ciphertext is decomposed with the decomposition base log and level count of the
+    /// GGSW ciphertext, and every decomposition term is multiplied with the corresponding level
+    /// matrix. The `output` ciphertext is accumulated into, so it must contain a trivial
+    /// encryption of zero to get the external product alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three ciphertexts do not have the same polynomial size and GLWE size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Plaintext, PlaintextList};
+    /// use tfhe::core_crypto::commons::crypto::ggsw::StandardGgswCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// let mut ggsw = StandardGgswCiphertext::allocate(
+    ///     0u64,
+    ///     PolynomialSize(4),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// ggsw.fill_with_trivial_encryption(&Plaintext(1));
+    /// let messages = vec![1u64 << 60, 2 << 60, 3 << 60, 4 << 60];
+    /// let glwe = GlweCiphertext::new_trivial_encryption(
+    ///     GlweSize(2),
+    ///     &PlaintextList::from_container(messages.clone()),
+    /// );
+    /// let mut output = GlweCiphertext::allocate(0u64, PolynomialSize(4), GlweSize(2));
+    /// ggsw.external_product(&mut output, &glwe);
+    /// assert_eq!(
+    ///     output.get_body().as_tensor().as_slice(),
+    ///     messages.as_slice()
+    /// );
+    /// ```
+    pub fn external_product<OutCont, InCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        glwe: &GlweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        assert_eq!(
+            self.polynomial_size(),
+            glwe.polynomial_size(),
+            "The GGSW and input GLWE ciphertexts have different polynomial sizes."
+        );
+        assert_eq!(
+            self.polynomial_size(),
+            output.polynomial_size(),
+            "The GGSW and output GLWE ciphertexts have different polynomial sizes."
+        );
+        assert_eq!(
+            self.glwe_size(),
+            glwe.size(),
+            "The GGSW and input GLWE ciphertexts have different GLWE sizes."
+        );
+        assert_eq!(
+            self.glwe_size(),
+            output.size(),
+            "The GGSW and output GLWE ciphertexts have different GLWE sizes."
+        );
+        let decomposer = SignedDecomposer::new(
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+        );
+        let mut decomposition = decomposer.decompose_tensor(glwe);
+        let mut product = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+        // The decomposition yields the terms from the last level to the first one, we iterate
+        // over the level matrices in the same order.
+        for matrix in self.level_matrix_iter().rev() {
+            let term = decomposition.next_term().unwrap();
+            debug_assert_eq!(term.level(), matrix.decomposition_level());
+            let term = GlweCiphertext::from_container(term.as_tensor().as_slice(), self.poly_size);
+            // We add the product of every row of the matrix with the matching polynomial of the
+            // decomposed GLWE ciphertext to the output.
+            for (row, term_polynomial) in matrix
+                .row_iter()
+                .zip(term.as_polynomial_list().polynomial_iter())
+            {
+                let row = row.into_glwe();
+                for (mut output_polynomial, row_polynomial) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(row.as_polynomial_list().polynomial_iter())
+                {
+                    product.fill_with_wrapping_mul(&row_polynomial, &term_polynomial);
+                    output_polynomial.update_with_wrapping_add(&product);
+                }
+            }
+        }
+    }
+
+    /// Selects between two GLWE ciphertexts under the bit encrypted in the GGSW ciphertext.
+    ///
+    /// The `ct_false` ciphertext is updated in place with
+    /// `ct_false + GGSW ⊡ (ct_true - ct_false)`, which encrypts the message of `ct_false` when
+    /// the GGSW ciphertext encrypts 0, and the message of `ct_true` when it encrypts 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three ciphertexts do not have the same polynomial size and GLWE size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Plaintext, PlaintextList};
+    /// use tfhe::core_crypto::commons::crypto::ggsw::StandardGgswCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// let mut ggsw = StandardGgswCiphertext::allocate(
+    ///     0u64,
+    ///     PolynomialSize(4),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// ggsw.fill_with_trivial_encryption(&Plaintext(1));
+    /// let mut ct_false = GlweCiphertext::new_trivial_encryption(
+    ///     GlweSize(2),
+    ///     &PlaintextList::from_container(vec![1u64 << 60; 4]),
+    /// );
+    /// let ct_true = GlweCiphertext::new_trivial_encryption(
+    ///     GlweSize(2),
+    ///     &PlaintextList::from_container(vec![2u64 << 60; 4]),
+    /// );
+    /// ggsw.cmux(&mut ct_false, &ct_true);
+    /// assert_eq!(ct_false.get_body().as_tensor().as_slice(), &[2 << 60; 4]);
+    /// ```
+    pub fn cmux<FalseCont, TrueCont, Scalar>(
+        &self,
+        ct_false: &mut GlweCiphertext<FalseCont>,
+        ct_true: &GlweCiphertext<TrueCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<FalseCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<TrueCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut difference =
+            GlweCiphertext::allocate(Scalar::ZERO, ct_true.polynomial_size(), ct_true.size());
+        difference
+            .as_mut_tensor()
+            .fill_with_copy(ct_true.as_tensor());
+        difference.update_with_sub(ct_false);
+        self.external_product(ct_false, &difference);
+    }
+
     pub fn fill_with_trivial_encryption<Scalar>(&mut self, plaintext: &Plaintext<Scalar>)
     where
         Self: AsMutTensor<Element = Scalar>,
@@ -1,12 +1,18 @@
-use crate::core_crypto::commons::crypto::encoding::PlaintextList;
+use crate::core_crypto::commons::crypto::encoding::{Plaintext, PlaintextList};
+use crate::core_crypto::commons::crypto::glwe::GlweCiphertext;
 use crate::core_crypto::commons::crypto::secret::generators::{
     DeterministicSeeder, EncryptionRandomGenerator,
 };
 use crate::core_crypto::commons::crypto::secret::GlweSecretKey;
 use crate::core_crypto::commons::math::random::{CompressionSeed, Seeder};
+use crate::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::{CastFrom, Numeric};
 use crate::core_crypto::commons::test_tools;
-use crate::core_crypto::prelude::{DecompositionBaseLog, DecompositionLevelCount, LogStandardDev};
+use crate::core_crypto::prelude::{
+    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LogStandardDev,
+    PolynomialSize,
+};
 use concrete_csprng::generators::SoftwareRandomGenerator;
 
 use super::{StandardGgswCiphertext, StandardGgswSeededCiphertext};
@@ -205,3 +211,269 @@ mod parallel {
         test_par_seeded_ggsw::<u64>()
     }
 }
+
+fn test_ggsw_from_glwe_ciphertexts<T: UnsignedTorus>() {
+    // random settings
+    let glwe_size = test_tools::random_glwe_dimension(5).to_glwe_size();
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let decomp_level = DecompositionLevelCount(3);
+    let decomp_base_log = DecompositionBaseLog(7);
+    let mut generator = test_tools::new_random_generator();
+
+    // generates random glwe ciphertexts
+    let ciphertexts: Vec<GlweCiphertext<Vec<T>>> = (0..decomp_level.0 * glwe_size.0)
+        .map(|_| {
+            GlweCiphertext::from_container(
+                generator
+                    .random_uniform_tensor(glwe_size.0 * polynomial_size.0)
+                    .into_container(),
+                polynomial_size,
+            )
+        })
+        .collect();
+
+    let ggsw = StandardGgswCiphertext::from_glwe_ciphertexts(
+        ciphertexts.clone(),
+        decomp_base_log,
+        decomp_level,
+    )
+    .unwrap();
+    assert_eq!(ggsw.decomposition_level_count(), decomp_level);
+    assert_eq!(ggsw.decomposition_base_log(), decomp_base_log);
+
+    // recovers the inputs by iterating over the levels
+    let recovered: Vec<Vec<T>> = ggsw
+        .level_matrix_iter()
+        .flat_map(|level| {
+            level
+                .row_iter()
+                .map(|row| row.as_tensor().as_slice().to_vec())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let expected: Vec<Vec<T>> = ciphertexts
+        .iter()
+        .map(|ct| ct.as_tensor().as_slice().to_vec())
+        .collect();
+    assert_eq!(recovered, expected);
+}
+
+#[test]
+fn test_ggsw_from_glwe_ciphertexts_u32() {
+    test_ggsw_from_glwe_ciphertexts::<u32>()
+}
+
+#[test]
+fn test_ggsw_from_glwe_ciphertexts_u64() {
+    test_ggsw_from_glwe_ciphertexts::<u64>()
+}
+
+fn test_ggsw_level_matrices_roundtrip<T: UnsignedTorus>() {
+    // random settings
+    let glwe_size = test_tools::random_glwe_dimension(5).to_glwe_size();
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let decomp_level = DecompositionLevelCount(3);
+    let decomp_base_log = DecompositionBaseLog(7);
+    let mut generator = test_tools::new_random_generator();
+
+    // generates a random ggsw ciphertext
+    let ggsw = StandardGgswCiphertext::from_container(
+        generator
+            .random_uniform_tensor::<T>(
+                decomp_level.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0,
+            )
+            .into_container(),
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+    );
+
+    let matrices = ggsw.clone().into_level_matrices();
+    assert_eq!(matrices.len(), decomp_level.0);
+    let reassembled =
+        StandardGgswCiphertext::from_level_matrices(matrices, decomp_base_log).unwrap();
+    assert_eq!(reassembled, ggsw);
+}
+
+#[test]
+fn test_ggsw_level_matrices_roundtrip_u32() {
+    test_ggsw_level_matrices_roundtrip::<u32>()
+}
+
+#[test]
+fn test_ggsw_level_matrices_roundtrip_u64() {
+    test_ggsw_level_matrices_roundtrip::<u64>()
+}
+
+#[test]
+fn test_ggsw_level_row_indices() {
+    use crate::core_crypto::commons::math::decomposition::DecompositionLevel;
+
+    let glwe_size = GlweSize(4);
+    let mut matrix = super::GgswLevelMatrix::from_container(
+        vec![0_u64; glwe_size.0 * glwe_size.0 * 16],
+        PolynomialSize(16),
+        glwe_size,
+        DecompositionLevel(1),
+    );
+    let expected: Vec<_> = (0..glwe_size.0).map(Some).collect();
+    let indices: Vec<_> = matrix.row_iter().map(|row| row.row_index()).collect();
+    assert_eq!(indices, expected);
+    let indices: Vec<_> = matrix.row_iter_mut().map(|row| row.row_index()).collect();
+    assert_eq!(indices, expected);
+}
+
+#[cfg(feature = "__commons_parallel")]
+#[test]
+fn test_ggsw_level_par_row_iter_order() {
+    use crate::core_crypto::commons::math::decomposition::DecompositionLevel;
+    use rayon::prelude::*;
+
+    let glwe_size = GlweSize(4);
+    let poly_size = PolynomialSize(16);
+    let matrix = super::GgswLevelMatrix::from_container(
+        (0..(glwe_size.0 * glwe_size.0 * poly_size.0) as u64).collect::<Vec<_>>(),
+        poly_size,
+        glwe_size,
+        DecompositionLevel(1),
+    );
+    let sequential: Vec<_> = matrix
+        .row_iter()
+        .map(|row| (row.row_index(), row.as_tensor().as_container().to_vec()))
+        .collect();
+    let parallel: Vec<_> = matrix
+        .par_row_iter()
+        .map(|row| (row.row_index(), row.as_tensor().as_container().to_vec()))
+        .collect();
+    assert_eq!(parallel, sequential);
+}
+
+// The keys and generators shared by the external product and cmux tests, which encrypt 4 bits
+// messages in GLWE ciphertexts, and constants in GGSW ciphertexts.
+struct ExternalProductContext<T: UnsignedTorus> {
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    secret_key: GlweSecretKey<BinaryKeyKind, Vec<T>>,
+    encryption_generator: EncryptionRandomGenerator<SoftwareRandomGenerator>,
+}
+
+impl<T: UnsignedTorus> ExternalProductContext<T> {
+    const NOISE: LogStandardDev = LogStandardDev(-50.);
+    const SHIFT: usize = <T as Numeric>::BITS - 4;
+
+    fn new() -> Self {
+        let glwe_dimension = test_tools::random_glwe_dimension(5);
+        let polynomial_size = test_tools::random_polynomial_size(200);
+        let mut secret_generator = test_tools::new_secret_random_generator();
+        let secret_key =
+            GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+        ExternalProductContext {
+            polynomial_size,
+            glwe_size: glwe_dimension.to_glwe_size(),
+            secret_key,
+            encryption_generator: test_tools::new_encryption_random_generator(),
+        }
+    }
+
+    // Returns the 4 bits messages `(i + offset) % 16`, for each coefficient `i`.
+    fn messages(&self, offset: usize) -> Vec<T> {
+        (0..self.polynomial_size.0)
+            .map(|i| <T as CastFrom<f64>>::cast_from(((i + offset) % 16) as f64))
+            .collect()
+    }
+
+    fn encrypt_constant_ggsw(&mut self, constant: T) -> StandardGgswCiphertext<Vec<T>> {
+        let mut ggsw = StandardGgswCiphertext::allocate(
+            T::ZERO,
+            self.polynomial_size,
+            self.glwe_size,
+            DecompositionLevelCount(3),
+            DecompositionBaseLog(7),
+        );
+        self.secret_key.encrypt_constant_ggsw(
+            &mut ggsw,
+            &Plaintext(constant),
+            Self::NOISE,
+            &mut self.encryption_generator,
+        );
+        ggsw
+    }
+
+    fn encrypt_glwe(&mut self, messages: &[T]) -> GlweCiphertext<Vec<T>> {
+        let plaintexts = PlaintextList::from_container(
+            messages
+                .iter()
+                .map(|m| *m << Self::SHIFT)
+                .collect::<Vec<_>>(),
+        );
+        let mut glwe = GlweCiphertext::allocate(T::ZERO, self.polynomial_size, self.glwe_size);
+        self.secret_key.encrypt_glwe(
+            &mut glwe,
+            &plaintexts,
+            Self::NOISE,
+            &mut self.encryption_generator,
+        );
+        glwe
+    }
+
+    // Decrypts and decodes the 4 bits messages of a GLWE ciphertext.
+    fn decrypt_glwe(&self, glwe: &GlweCiphertext<Vec<T>>) -> Vec<T> {
+        let mut decrypted = PlaintextList::from_container(vec![T::ZERO; self.polynomial_size.0]);
+        self.secret_key.decrypt_glwe(&mut decrypted, glwe);
+        decrypted
+            .as_tensor()
+            .iter()
+            .map(|p| p.wrapping_add(T::ONE << (Self::SHIFT - 1)) >> Self::SHIFT)
+            .collect()
+    }
+}
+
+fn test_external_product_with_one<T: UnsignedTorus>() {
+    let mut ctx = ExternalProductContext::<T>::new();
+    let ggsw = ctx.encrypt_constant_ggsw(T::ONE);
+    let messages = ctx.messages(0);
+    let glwe = ctx.encrypt_glwe(&messages);
+
+    // the input is borrowed, while the output is owned
+    let mut output = GlweCiphertext::allocate(T::ZERO, ctx.polynomial_size, ctx.glwe_size);
+    ggsw.external_product(&mut output, &glwe.as_view());
+
+    assert_eq!(ctx.decrypt_glwe(&output), messages);
+}
+
+#[test]
+fn test_external_product_with_one_u32() {
+    test_external_product_with_one::<u32>()
+}
+
+#[test]
+fn test_external_product_with_one_u64() {
+    test_external_product_with_one::<u64>()
+}
+
+fn test_cmux<T: UnsignedTorus>() {
+    let mut ctx = ExternalProductContext::<T>::new();
+    let false_messages = ctx.messages(0);
+    let true_messages = ctx.messages(5);
+    let ct_false = ctx.encrypt_glwe(&false_messages);
+    let ct_true = ctx.encrypt_glwe(&true_messages);
+
+    for (bit, expected) in [(T::ZERO, &false_messages), (T::ONE, &true_messages)] {
+        let ggsw = ctx.encrypt_constant_ggsw(bit);
+
+        let mut output = ct_false.clone();
+        ggsw.cmux(&mut output, &ct_true.as_view());
+
+        assert_eq!(&ctx.decrypt_glwe(&output), expected);
+    }
+}
+
+#[test]
+fn test_cmux_u32() {
+    test_cmux::<u32>()
+}
+
+#[test]
+fn test_cmux_u64() {
+    test_cmux::<u64>()
+}
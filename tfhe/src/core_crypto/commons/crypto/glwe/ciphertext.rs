@@ -10,6 +10,43 @@ use crate::core_crypto::commons::numeric::Numeric;
 use crate::core_crypto::prelude::{GlweDimension, GlweSize, MonomialDegree, PolynomialSize};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur when two GLWE ciphertexts do not have the same shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlweShapeError {
+    /// The two ciphertexts do not have the same GLWE size.
+    GlweSize { expected: GlweSize, got: GlweSize },
+    /// The two ciphertexts do not have the same polynomial size.
+    PolynomialSize {
+        expected: PolynomialSize,
+        got: PolynomialSize,
+    },
+}
+
+impl Display for GlweShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GlweShapeError::GlweSize { expected, got } => {
+                write!(
+                    f,
+                    "Expected a GLWE ciphertext of size {}, got {}.",
+                    expected.0, got.0
+                )
+            }
+            GlweShapeError::PolynomialSize { expected, got } => {
+                write!(
+                    f,
+                    "Expected a GLWE ciphertext of polynomial size {}, got {}.",
+                    expected.0, got.0
+                )
+            }
+        }
+    }
+}
+
+impl Error for GlweShapeError {}
 
 /// An GLWE ciphertext.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -408,6 +445,21 @@ impl<Cont> GlweCiphertext<Cont> {
     /// Fills an LWE ciphertext with the extraction of one coefficient of the current GLWE
     /// ciphertext.
     ///
+    /// # Note
+    ///
+    /// The output LWE dimension must be equal to `glwe_dimension * poly_size`.
+    ///
+    /// Extracting the coefficient of degree `n_th` of the product of a mask polynomial $A$ with a
+    /// key polynomial $S$ in $\mathbb{Z}_q\[X\]/(X^N+1)$ reads $\sum_j a_{n-j}s_j$. For
+    /// $j \leq n$, the coefficient $a_{n-j}$ is taken as is, while for $j > n$ the index wraps
+    /// past the polynomial degree and $X^N = -1$ gives the coefficient $-a_{N+n-j}$. Each mask
+    /// polynomial of the output is then the reversed GLWE mask polynomial, rotated to the right by
+    /// $n+1$, with its last $N - n - 1$ coefficients negated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output LWE dimension is not `glwe_dimension * poly_size`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -468,6 +520,11 @@ impl<Cont> GlweCiphertext<Cont> {
         LweCiphertext<OutputCont>: AsMutTensor<Element = Element>,
         Element: UnsignedTorus,
     {
+        assert_eq!(
+            lwe.lwe_size().to_lwe_dimension().0,
+            self.mask_size().0 * self.poly_size.0,
+            "The output LWE dimension must be equal to glwe_dimension * poly_size."
+        );
         // We retrieve the bodies and masks of the two ciphertexts.
         let (lwe_body, mut lwe_mask) = lwe.get_mut_body_and_mask();
         let (glwe_body, glwe_mask) = self.get_body_and_mask();
@@ -503,6 +560,34 @@ impl<Cont> GlweCiphertext<Cont> {
         }
     }
 
+    /// Fills the ciphertext with a trivial encryption of the plaintexts: the mask is set to zero
+    /// and the plaintexts are copied to the body.
+    ///
+    /// # Note
+    ///
+    /// A trivial encryption is not secure, and only meant to inject public values in homomorphic
+    /// computations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of plaintexts is not equal to the polynomial size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let plaintexts = PlaintextList::from_container((0..8u32).collect::<Vec<_>>());
+    /// let mut ciphertext = GlweCiphertext::allocate(9u32, PolynomialSize(8), GlweSize(3));
+    /// ciphertext.fill_with_trivial_encryption(&plaintexts);
+    /// assert_eq!(
+    ///     ciphertext.get_body().as_tensor().as_slice(),
+    ///     plaintexts.as_tensor().as_slice()
+    /// );
+    /// assert!(ciphertext.get_mask().as_tensor().iter().all(|a| *a == 0));
+    /// ```
     pub fn fill_with_trivial_encryption<PlaintextContainer, Scalar>(
         &mut self,
         plaintexts: &PlaintextList<PlaintextContainer>,
@@ -511,7 +596,11 @@ impl<Cont> GlweCiphertext<Cont> {
         Self: AsMutTensor<Element = Scalar>,
         Scalar: Numeric,
     {
-        debug_assert_eq!(plaintexts.count().0, self.poly_size.0);
+        assert_eq!(
+            plaintexts.count().0,
+            self.poly_size.0,
+            "The number of plaintexts must be equal to the polynomial size."
+        );
         let (mut body, mut mask) = self.get_mut_body_and_mask();
 
         mask.as_mut_polynomial_list()
@@ -531,4 +620,502 @@ impl<Cont> GlweCiphertext<Cont> {
                 },
             );
     }
+
+    /// Returns whether the ciphertext is a trivial encryption, i.e. whether all the coefficients
+    /// of its mask are zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let trivial = GlweCiphertext::new_trivial_encryption(
+    ///     GlweSize(3),
+    ///     &PlaintextList::from_container(vec![1u32, 2, 3, 4]),
+    /// );
+    /// assert!(trivial.is_trivial());
+    /// let not_trivial = GlweCiphertext::allocate(1u32, PolynomialSize(4), GlweSize(3));
+    /// assert!(!not_trivial.is_trivial());
+    /// ```
+    pub fn is_trivial(&self) -> bool
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Numeric,
+    {
+        self.get_mask()
+            .as_tensor()
+            .iter()
+            .all(|coef| *coef == <<Self as AsRefTensor>::Element as Numeric>::ZERO)
+    }
+
+    /// Decrypts a trivial ciphertext, by returning its body.
+    ///
+    /// Since the mask of a trivial ciphertext is zero, its decryption does not depend on the
+    /// secret key, and no product with the key needs to be computed.
+    ///
+    /// # Note
+    ///
+    /// The ciphertext is only checked to be trivial in debug builds. The result is meaningless
+    /// for a ciphertext which is not trivial.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(4), &mut secret_generator);
+    /// let ciphertext = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![1u32, 2, 3, 4]),
+    /// );
+    /// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+    /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+    /// assert_eq!(ciphertext.trivial_decrypt(), decrypted);
+    /// ```
+    pub fn trivial_decrypt(&self) -> PlaintextList<Vec<<Self as AsRefTensor>::Element>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Numeric,
+    {
+        debug_assert!(
+            self.is_trivial(),
+            "Tried to trivially decrypt a ciphertext which is not trivial."
+        );
+        PlaintextList::from_container(self.get_body().as_tensor().as_slice().to_vec())
+    }
+
+    /// Zeroes the mask of the ciphertext, turning it into a trivial encryption of its current
+    /// body.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let mut ciphertext = GlweCiphertext::allocate(7u32, PolynomialSize(4), GlweSize(3));
+    /// assert!(!ciphertext.is_trivial());
+    /// ciphertext.make_trivial();
+    /// assert!(ciphertext.is_trivial());
+    /// assert_eq!(ciphertext.get_body().as_tensor().as_slice(), &[7, 7, 7, 7]);
+    /// ```
+    pub fn make_trivial(&mut self)
+    where
+        Self: AsMutTensor,
+        <Self as AsMutTensor>::Element: Numeric,
+    {
+        self.get_mut_mask()
+            .as_mut_tensor()
+            .fill_with_element(<<Self as AsMutTensor>::Element as Numeric>::ZERO);
+    }
+
+    /// Adds the `other` ciphertext to the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two ciphertexts do not have the same polynomial size and GLWE size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(4), &mut secret_generator);
+    /// let mut first = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![1u32, 2, 3, 4]),
+    /// );
+    /// let second = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![10u32, 20, 30, 40]),
+    /// );
+    /// first.update_with_add(&second);
+    /// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+    /// secret_key.decrypt_glwe(&mut decrypted, &first);
+    /// assert_eq!(decrypted.as_tensor().as_slice(), &[11, 22, 33, 44]);
+    /// ```
+    pub fn update_with_add<OtherCont, Scalar>(&mut self, other: &GlweCiphertext<OtherCont>)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.check_same_shape(other);
+        self.as_mut_tensor()
+            .update_with_wrapping_add(other.as_tensor())
+    }
+
+    /// Adds the `other` ciphertext to the current one, if the two ciphertexts have the same shape.
+    ///
+    /// Contrary to [`update_with_add`](`GlweCiphertext::update_with_add`), this does not panic
+    /// on ciphertexts of different shapes: an error is returned instead, and the current
+    /// ciphertext is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::{GlweCiphertext, GlweShapeError};
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, GlweSize, PolynomialSize};
+    /// let mut first = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![1u32, 2, 3, 4]),
+    /// );
+    /// let second = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![10u32, 20, 30, 40]),
+    /// );
+    /// assert_eq!(first.try_update_with_wrapping_add(&second), Ok(()));
+    /// assert_eq!(&first.as_tensor().as_slice()[8..], &[11, 22, 33, 44]);
+    ///
+    /// let smaller = GlweCiphertext::allocate(0u32, PolynomialSize(2), GlweSize(3));
+    /// assert_eq!(
+    ///     first.try_update_with_wrapping_add(&smaller),
+    ///     Err(GlweShapeError::PolynomialSize {
+    ///         expected: PolynomialSize(4),
+    ///         got: PolynomialSize(2)
+    ///     })
+    /// );
+    /// let thinner = GlweCiphertext::allocate(0u32, PolynomialSize(4), GlweSize(2));
+    /// assert_eq!(
+    ///     first.try_update_with_wrapping_add(&thinner),
+    ///     Err(GlweShapeError::GlweSize {
+    ///         expected: GlweSize(3),
+    ///         got: GlweSize(2)
+    ///     })
+    /// );
+    /// assert_eq!(&first.as_tensor().as_slice()[8..], &[11, 22, 33, 44]);
+    /// ```
+    pub fn try_update_with_wrapping_add<OtherCont, Scalar>(
+        &mut self,
+        other: &GlweCiphertext<OtherCont>,
+    ) -> Result<(), GlweShapeError>
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        if self.polynomial_size() != other.polynomial_size() {
+            return Err(GlweShapeError::PolynomialSize {
+                expected: self.polynomial_size(),
+                got: other.polynomial_size(),
+            });
+        }
+        if self.size() != other.size() {
+            return Err(GlweShapeError::GlweSize {
+                expected: self.size(),
+                got: other.size(),
+            });
+        }
+        self.update_with_add(other);
+        Ok(())
+    }
+
+    /// Subtracts the `other` ciphertext from the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two ciphertexts do not have the same polynomial size and GLWE size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(4), &mut secret_generator);
+    /// let mut first = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![10u32, 20, 30, 40]),
+    /// );
+    /// let second = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![1u32, 2, 3, 4]),
+    /// );
+    /// first.update_with_sub(&second);
+    /// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+    /// secret_key.decrypt_glwe(&mut decrypted, &first);
+    /// assert_eq!(decrypted.as_tensor().as_slice(), &[9, 18, 27, 36]);
+    /// ```
+    pub fn update_with_sub<OtherCont, Scalar>(&mut self, other: &GlweCiphertext<OtherCont>)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.check_same_shape(other);
+        self.as_mut_tensor()
+            .update_with_wrapping_sub(other.as_tensor())
+    }
+
+    /// Re-encodes the message of the ciphertext from the scaling factor `from_delta` to the
+    /// scaling factor `to_delta`, by multiplying the ciphertext by `to_delta / from_delta`.
+    ///
+    /// # Note
+    ///
+    /// The noise of the ciphertext is multiplied by the same factor, which means its variance is
+    /// multiplied by the square of `to_delta / from_delta`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from_delta` is zero, or does not divide `to_delta`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(4), &mut secret_generator);
+    /// let mut ciphertext = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![1u64 << 60, 2 << 60, 3 << 60, 0]),
+    /// );
+    /// ciphertext.rescale(1 << 60, 1 << 62);
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(4));
+    /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+    /// assert_eq!(
+    ///     decrypted.as_tensor().as_slice(),
+    ///     &[1 << 62, 2 << 62, 3 << 62, 0]
+    /// );
+    /// ```
+    pub fn rescale<Scalar>(&mut self, from_delta: Scalar, to_delta: Scalar)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        assert_ne!(
+            from_delta,
+            Scalar::ZERO,
+            "The source scaling factor cannot be 0."
+        );
+        assert_eq!(
+            to_delta % from_delta,
+            Scalar::ZERO,
+            "The source scaling factor must divide the target scaling factor."
+        );
+        self.as_mut_tensor()
+            .update_with_wrapping_scalar_mul(&(to_delta / from_delta))
+    }
+
+    /// Multiplies the ciphertext by the monomial $X^k$, which shifts the encrypted coefficients
+    /// by `k` positions.
+    ///
+    /// This is the ciphertext counterpart of
+    /// [`update_with_wrapping_monic_monomial_mul`](`crate::core_crypto::commons::math::polynomial::Polynomial::update_with_wrapping_monic_monomial_mul`),
+    /// applied to every polynomial of the mask and of the body.
+    ///
+    /// # Note
+    ///
+    /// The product is computed modulo $X^N+1$, so that every coefficient wrapping around the end
+    /// of the polynomial is negated: the coefficient of index $i$ is sent to index
+    /// $(i + k) \bmod N$, with a sign of $(-1)^{\lfloor (i + k) / N \rfloor}$. A purely cyclic
+    /// shift is not compatible with the ring structure, and can not be applied to a ciphertext.
+    /// The rotation has a period of $2N$: shifting by `2 * N` returns the original ciphertext,
+    /// while shifting by `N` negates it. The noise is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, MonomialDegree};
+    /// let ciphertext = GlweCiphertext::new_trivial_encryption(
+    ///     GlweDimension(2).to_glwe_size(),
+    ///     &PlaintextList::from_container(vec![1u32, 2, 3, 4]),
+    /// );
+    /// let mut shifted = ciphertext.clone();
+    /// shifted.update_with_wrapping_monic_monomial_mul(MonomialDegree(1));
+    /// assert_eq!(
+    ///     shifted.get_body().as_tensor().as_slice(),
+    ///     &[4u32.wrapping_neg(), 1, 2, 3]
+    /// );
+    /// shifted.update_with_wrapping_monic_monomial_mul(MonomialDegree(4));
+    /// assert_eq!(
+    ///     shifted.get_body().as_tensor().as_slice(),
+    ///     &[
+    ///         4,
+    ///         1u32.wrapping_neg(),
+    ///         2u32.wrapping_neg(),
+    ///         3u32.wrapping_neg()
+    ///     ]
+    /// );
+    /// shifted.update_with_wrapping_monic_monomial_mul(MonomialDegree(3));
+    /// assert_eq!(shifted, ciphertext);
+    /// ```
+    pub fn update_with_wrapping_monic_monomial_mul<Scalar>(&mut self, k: MonomialDegree)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        for mut polynomial in self.as_mut_polynomial_list().polynomial_iter_mut() {
+            polynomial.update_with_wrapping_monic_monomial_mul(k);
+        }
+    }
+
+    /// Fills the ciphertext with a trivial encryption of the accumulator of a programmable
+    /// bootstrap evaluating the lookup table `lut`.
+    ///
+    /// The mask is set to zero, and the body is filled with a redundant step function: each entry
+    /// of the lookup table is repeated over a box of `poly_size / message_modulus` coefficients.
+    /// The body is then rotated by half a box, negating the coefficients which wrap around, so
+    /// that the noise of the input ciphertext, in either direction, stays in the box of its
+    /// message.
+    ///
+    /// After a blind rotation by $X^{-m \cdot box\_size}$, the constant coefficient of the body
+    /// is `lut[m]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the lookup table is not `message_modulus`, or if `message_modulus`
+    /// does not divide the polynomial size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweSize, PolynomialSize};
+    /// let mut accumulator = GlweCiphertext::allocate(7u32, PolynomialSize(8), GlweSize(2));
+    /// accumulator.fill_with_lookup_table_accumulator(&[10, 20, 30, 40], 4);
+    /// assert!(accumulator.is_trivial());
+    /// assert_eq!(
+    ///     accumulator.get_body().as_tensor().as_slice(),
+    ///     &[10, 20, 20, 30, 30, 40, 40, 10u32.wrapping_neg()]
+    /// );
+    /// ```
+    pub fn fill_with_lookup_table_accumulator<Scalar>(
+        &mut self,
+        lut: &[Scalar],
+        message_modulus: usize,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        assert_eq!(
+            lut.len(),
+            message_modulus,
+            "The lookup table must contain one entry per message."
+        );
+        assert!(
+            message_modulus != 0 && self.poly_size.0 % message_modulus == 0,
+            "The message modulus {} does not divide the polynomial size {}.",
+            message_modulus,
+            self.poly_size.0
+        );
+        let box_size = self.poly_size.0 / message_modulus;
+        let half_box_size = box_size / 2;
+
+        self.make_trivial();
+        let mut body = self.get_mut_body();
+        let body = body.as_mut_tensor().as_mut_slice();
+        for (body_box, value) in body.chunks_exact_mut(box_size).zip(lut.iter()) {
+            body_box.fill(*value);
+        }
+
+        // The first half box wraps around the negacyclic ring when rotated.
+        for coefficient in body[0..half_box_size].iter_mut() {
+            *coefficient = coefficient.wrapping_neg();
+        }
+        body.rotate_left(half_box_size);
+    }
+
+    fn check_same_shape<OtherCont>(&self, other: &GlweCiphertext<OtherCont>)
+    where
+        Self: AsRefTensor,
+        GlweCiphertext<OtherCont>: AsRefTensor,
+    {
+        assert_eq!(
+            self.polynomial_size(),
+            other.polynomial_size(),
+            "The two GLWE ciphertexts have different polynomial sizes."
+        );
+        assert_eq!(
+            self.size(),
+            other.size(),
+            "The two GLWE ciphertexts have different GLWE sizes."
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    use crate::core_crypto::commons::math::polynomial::Polynomial;
+    use crate::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    use crate::core_crypto::commons::math::torus::UnsignedTorus;
+    use crate::core_crypto::commons::test_tools::any_uint;
+    use crate::core_crypto::prelude::{GlweSize, MonomialDegree, PolynomialSize};
+
+    fn test_lookup_table_accumulator<T: UnsignedTorus>() {
+        let poly_size = PolynomialSize(1024);
+        for message_modulus in [2, 4, 8, 16] {
+            let lut: Vec<T> = (0..message_modulus).map(|_| any_uint()).collect();
+            let mut accumulator = GlweCiphertext::allocate(T::ONE, poly_size, GlweSize(3));
+            accumulator.fill_with_lookup_table_accumulator(&lut, message_modulus);
+            assert!(accumulator.is_trivial());
+
+            let box_size = poly_size.0 / message_modulus;
+            let half_box_size = box_size / 2;
+            for (message, expected) in lut.iter().enumerate() {
+                // Every input within half a box of the encoded message must select its entry,
+                // including the inputs below zero which only wrap around the negacyclic ring.
+                for offset in -(half_box_size as isize)..half_box_size as isize {
+                    let rotation = (message * box_size) as isize + offset;
+                    let rotation = rotation.rem_euclid(2 * poly_size.0 as isize) as usize;
+                    let mut body = Polynomial::from_container(
+                        accumulator.get_body().as_tensor().as_slice().to_vec(),
+                    );
+                    body.update_with_wrapping_monic_monomial_div(MonomialDegree(rotation));
+                    assert_eq!(
+                        body.as_tensor().first(),
+                        expected,
+                        "message {message}, offset {offset}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_table_accumulator_u32() {
+        test_lookup_table_accumulator::<u32>();
+    }
+
+    #[test]
+    fn test_lookup_table_accumulator_u64() {
+        test_lookup_table_accumulator::<u64>();
+    }
 }
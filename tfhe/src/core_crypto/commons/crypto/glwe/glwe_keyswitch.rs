@@ -0,0 +1,667 @@
+use super::{GlweCiphertext, GlweList};
+use crate::core_crypto::commons::crypto::encoding::PlaintextList;
+use crate::core_crypto::commons::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::crypto::secret::GlweSecretKey;
+use crate::core_crypto::commons::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::core_crypto::commons::math::polynomial::Polynomial;
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
+use crate::core_crypto::commons::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::prelude::{
+    BinaryKeyKind, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter,
+    GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
+};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A GLWE keyswitching key.
+///
+/// A GLWE keyswitching key allows to change the key of a GLWE ciphertext, while keeping the same
+/// polynomial size. Lets assume the following elements:
+///
+/// + The input key $S\_{in}$ is composed of $k\_{in}$ polynomials
+/// + The output key $S\_{out}$ is composed of $k\_{out}$ polynomials
+///
+/// The keyswitch key will be composed of $k\_{in}$ blocks of $l\_{ks}$ GLWE encryptions under
+/// $S\_{out}$, one for each level of the decomposition of a polynomial of $S\_{in}$ over a given
+/// basis $B\_{ks}\in\mathbb{N}$.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlweKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(GlweKeyswitchKey);
+
+impl<Scalar> GlweKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a GLWE keyswitching key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a keyswitch key, but merely allocates a container of the
+    /// right size. See [`GlweKeyswitchKey::fill_with_keyswitch_key`] to fill the container with a
+    /// proper keyswitching key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(10));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(ksk.input_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.output_glwe_key_dimension(), GlweDimension(3));
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: GlweDimension,
+        output_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> Self {
+        GlweKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0
+                    * output_dimension.to_glwe_size().0
+                    * poly_size.0
+                    * input_dimension.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_dimension.to_glwe_size(),
+            poly_size,
+        }
+    }
+}
+
+impl<Cont> GlweKeyswitchKey<Cont> {
+    /// Creates a GLWE keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a keyswitch key, but merely wraps the container in the proper
+    /// type. It assumes that either the container already contains a proper keyswitching key, or
+    /// that [`GlweKeyswitchKey::fill_with_keyswitch_key`] will be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::from_container(
+    ///     vec![0 as u8; 2 * 4 * 256 * 10],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.input_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(ksk.output_glwe_key_dimension(), GlweDimension(3));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> GlweKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => output_glwe_dimension.to_glwe_size().0 * poly_size.0, decomp_size.0);
+        GlweKeyswitchKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            poly_size,
+        }
+    }
+
+    /// Returns the dimension of the input GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.input_glwe_key_dimension(), GlweDimension(2));
+    /// ```
+    pub fn input_glwe_key_dimension(&self) -> GlweDimension
+    where
+        Self: AsRefTensor,
+    {
+        GlweDimension(
+            self.as_tensor().len()
+                / (self.output_glwe_size.0 * self.poly_size.0 * self.decomp_level_count.0),
+        )
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.output_glwe_key_dimension(), GlweDimension(3));
+    /// ```
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the input and output GLWE ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.decomposition_level_count(), DecompositionLevelCount(10));
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key polynomials.
+    ///
+    /// Indeed, the basis used is always of the form $2^b$. This function returns $b$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// let ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     GlweDimension(2),
+    ///     GlweDimension(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current keyswitch key container with an actual keyswitching key constructed from
+    /// an input and an output GLWE key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys do not have the dimensions and polynomial size of the keyswitch key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweKeyswitchKey;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::AsRefTensor;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let polynomial_size = PolynomialSize(256);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), polynomial_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(1), polynomial_size, &mut secret_generator);
+    ///
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     GlweDimension(2),
+    ///     GlweDimension(1),
+    ///     polynomial_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_keyswitch_key<InKeyCont, OutKeyCont, Scalar, Gen>(
+        &mut self,
+        input_key: &GlweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_key: &GlweSecretKey<BinaryKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        assert_eq!(
+            input_key.key_size(),
+            self.input_glwe_key_dimension(),
+            "The input key dimension does not match the input key dimension of the keyswitch key."
+        );
+        assert_eq!(
+            output_key.key_size(),
+            self.output_glwe_key_dimension(),
+            "The output key dimension does not match the output key dimension of the keyswitch \
+            key."
+        );
+        assert_eq!(
+            input_key.polynomial_size(),
+            self.poly_size,
+            "The input key polynomial size does not match the one of the keyswitch key."
+        );
+        assert_eq!(
+            output_key.polynomial_size(),
+            self.poly_size,
+            "The output key polynomial size does not match the one of the keyswitch key."
+        );
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::from_container(vec![
+            Scalar::ZERO;
+            self.decomp_level_count.0
+                * self.poly_size.0
+        ]);
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let poly_size = self.poly_size;
+
+        // loop over the polynomials of the input key
+        for (input_key_polynomial, mut keyswitch_key_block) in input_key
+            .as_polynomial_list()
+            .polynomial_iter()
+            .zip(self.polynomial_decomp_iter_mut())
+        {
+            // We fill the buffer with the powers of the key polynomial
+            for (level, mut message) in (1..=decomp_level_count.0)
+                .map(DecompositionLevel)
+                .zip(messages.sublist_iter_mut(PlaintextCount(poly_size.0)))
+            {
+                message.as_mut_tensor().fill_with_one(
+                    input_key_polynomial.as_tensor(),
+                    |key_coefficient| {
+                        DecompositionTerm::new(level, decomp_base_log, *key_coefficient)
+                            .to_recomposition_summand()
+                    },
+                );
+            }
+
+            // We encrypt the buffer
+            output_key.encrypt_glwe_list(
+                &mut keyswitch_key_block,
+                &messages,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
+    /// Iterates over the blocks of the key, each one being the list of GLWE ciphertexts
+    /// encrypting, under the output key, the $l$ levels of the decomposition of a polynomial of
+    /// the input key.
+    fn polynomial_decomp_iter(
+        &self,
+    ) -> impl Iterator<Item = GlweList<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let size = self.decomp_level_count.0 * self.output_glwe_size.0 * self.poly_size.0;
+        let glwe_dimension = self.output_glwe_size.to_glwe_dimension();
+        let poly_size = self.poly_size;
+        self.as_tensor().subtensor_iter(size).map(move |sub| {
+            GlweList::from_container(sub.into_container(), glwe_dimension, poly_size)
+        })
+    }
+
+    /// Iterates over the mutable blocks of the key, each one being the list of GLWE ciphertexts
+    /// encrypting, under the output key, the $l$ levels of the decomposition of a polynomial of
+    /// the input key.
+    fn polynomial_decomp_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweList<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let size = self.decomp_level_count.0 * self.output_glwe_size.0 * self.poly_size.0;
+        let glwe_dimension = self.output_glwe_size.to_glwe_dimension();
+        let poly_size = self.poly_size;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(size)
+            .map(move |sub| {
+                GlweList::from_container(sub.into_container(), glwe_dimension, poly_size)
+            })
+    }
+
+    /// Keyswitches a GLWE ciphertext encrypted under the input key into a GLWE ciphertext
+    /// encrypted under the output key.
+    ///
+    /// Each polynomial of the input mask is decomposed, and the products of the terms of its
+    /// decomposition with the matching ciphertexts of the key are subtracted from the input body.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the dimensions and polynomial size of the keyswitch
+    /// key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::{GlweCiphertext, GlweKeyswitchKey};
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::AsRefTensor;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+    ///     PolynomialSize,
+    /// };
+    ///
+    /// let input_dimension = GlweDimension(2);
+    /// let output_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key =
+    ///     GlweSecretKey::generate_binary(input_dimension, polynomial_size, &mut secret_generator);
+    /// let output_key =
+    ///     GlweSecretKey::generate_binary(output_dimension, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut ksk = GlweKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(8),
+    ///     DecompositionBaseLog(3),
+    ///     input_dimension,
+    ///     output_dimension,
+    ///     polynomial_size,
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+    ///
+    /// let plaintexts = PlaintextList::from_container(vec![3u64 << 60; polynomial_size.0]);
+    /// let mut ciphertext =
+    ///     GlweCiphertext::allocate(0u64, polynomial_size, input_dimension.to_glwe_size());
+    /// input_key.encrypt_glwe(
+    ///     &mut ciphertext,
+    ///     &plaintexts,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut switched =
+    ///     GlweCiphertext::allocate(0u64, polynomial_size, output_dimension.to_glwe_size());
+    /// ksk.keyswitch_glwe_ciphertext(&mut switched, &ciphertext);
+    ///
+    /// let mut decrypted = PlaintextList::from_container(vec![0u64; polynomial_size.0]);
+    /// output_key.decrypt_glwe(&mut decrypted, &switched);
+    /// for (dec, plain) in decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .zip(plaintexts.as_tensor().iter())
+    /// {
+    ///     let error = dec.wrapping_sub(*plain);
+    ///     assert!(error.min(error.wrapping_neg()) < 1 << 50);
+    /// }
+    /// ```
+    pub fn keyswitch_glwe_ciphertext<CO, CI, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<CO>,
+        input: &GlweCiphertext<CI>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<CO>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<CI>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        assert_eq!(
+            input.polynomial_size(),
+            output.polynomial_size(),
+            "The input and output GLWE ciphertexts have different polynomial sizes."
+        );
+        assert_eq!(
+            self.poly_size,
+            input.polynomial_size(),
+            "The input GLWE ciphertext polynomial size does not match the one of the keyswitch \
+            key."
+        );
+        assert_eq!(
+            self.input_glwe_key_dimension(),
+            input.mask_size(),
+            "The input GLWE ciphertext dimension does not match the input key dimension of the \
+            keyswitch key."
+        );
+        assert_eq!(
+            self.output_glwe_key_dimension(),
+            output.mask_size(),
+            "The output GLWE ciphertext dimension does not match the output key dimension of \
+            the keyswitch key."
+        );
+
+        // We reset the output
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+
+        // We copy the body
+        output
+            .get_mut_body()
+            .as_mut_tensor()
+            .fill_with_copy(input.get_body().as_tensor());
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut product = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+
+        for (block, input_mask_polynomial) in self
+            .polynomial_decomp_iter()
+            .zip(input.get_mask().as_polynomial_list().polynomial_iter())
+        {
+            let mut decomposition = decomposer.decompose_tensor(&input_mask_polynomial);
+            // The decomposition yields the terms from the last level to the first one, we iterate
+            // over the ciphertexts of the block in the same order.
+            for level_key_cipher in block
+                .as_tensor()
+                .subtensor_iter(self.output_glwe_size.0 * self.poly_size.0)
+                .rev()
+                .map(|sub| GlweCiphertext::from_container(sub.into_container(), self.poly_size))
+            {
+                let term = decomposition.next_term().unwrap();
+                let term = Polynomial::from_container(term.as_tensor().as_slice());
+                for (mut output_polynomial, key_polynomial) in output
+                    .as_mut_polynomial_list()
+                    .polynomial_iter_mut()
+                    .zip(level_key_cipher.as_polynomial_list().polynomial_iter())
+                {
+                    product.fill_with_wrapping_mul(&key_polynomial, &term);
+                    output_polynomial.update_with_wrapping_sub(&product);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_crypto::commons::crypto::encoding::PlaintextList;
+    use crate::core_crypto::commons::crypto::glwe::{GlweCiphertext, GlweKeyswitchKey};
+    use crate::core_crypto::commons::crypto::secret::GlweSecretKey;
+    use crate::core_crypto::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    use crate::core_crypto::commons::math::torus::UnsignedTorus;
+    use crate::core_crypto::commons::test_tools::{
+        any_uint, new_encryption_random_generator, new_secret_random_generator,
+    };
+    use crate::core_crypto::prelude::{
+        DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LogStandardDev,
+        PlaintextCount, PolynomialSize,
+    };
+
+    fn test_keyswitch_preserves_decryption<T: UnsignedTorus>() {
+        let polynomial_size = PolynomialSize(256);
+        let noise = LogStandardDev::from_log_standard_dev(-25.);
+        let mut secret_generator = new_secret_random_generator();
+        let mut encryption_generator = new_encryption_random_generator();
+
+        for (input_dimension, output_dimension) in [(2, 1), (1, 3)] {
+            let input_dimension = GlweDimension(input_dimension);
+            let output_dimension = GlweDimension(output_dimension);
+            let input_key = GlweSecretKey::generate_binary(
+                input_dimension,
+                polynomial_size,
+                &mut secret_generator,
+            );
+            let output_key = GlweSecretKey::generate_binary(
+                output_dimension,
+                polynomial_size,
+                &mut secret_generator,
+            );
+            let mut ksk = GlweKeyswitchKey::allocate(
+                T::ZERO,
+                DecompositionLevelCount(5),
+                DecompositionBaseLog(4),
+                input_dimension,
+                output_dimension,
+                polynomial_size,
+            );
+            ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
+
+            let mut plaintexts =
+                PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+            plaintexts
+                .as_mut_tensor()
+                .fill_with(|| any_uint::<T>() & (T::MAX << (T::BITS - 4)));
+            let mut ciphertext =
+                GlweCiphertext::allocate(T::ZERO, polynomial_size, input_dimension.to_glwe_size());
+            input_key.encrypt_glwe(
+                &mut ciphertext,
+                &plaintexts,
+                noise,
+                &mut encryption_generator,
+            );
+
+            let mut switched =
+                GlweCiphertext::allocate(T::ZERO, polynomial_size, output_dimension.to_glwe_size());
+            ksk.keyswitch_glwe_ciphertext(&mut switched, &ciphertext);
+
+            let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+            output_key.decrypt_glwe(&mut decrypted, &switched);
+            for (dec, plain) in decrypted
+                .as_tensor()
+                .iter()
+                .zip(plaintexts.as_tensor().iter())
+            {
+                let error = dec.wrapping_sub(*plain);
+                assert!(error.min(error.wrapping_neg()) < T::ONE << (T::BITS - 8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyswitch_preserves_decryption_u32() {
+        test_keyswitch_preserves_decryption::<u32>();
+    }
+
+    #[test]
+    fn test_keyswitch_preserves_decryption_u64() {
+        test_keyswitch_preserves_decryption::<u64>();
+    }
+}
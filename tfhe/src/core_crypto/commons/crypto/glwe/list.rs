@@ -1,14 +1,18 @@
-use super::GlweCiphertext;
+use super::{GlweCiphertext, StridedGlweList};
 use crate::core_crypto::commons::crypto::encoding::PlaintextList;
+use crate::core_crypto::commons::crypto::secret::GlweSecretKey;
 use crate::core_crypto::commons::math::tensor::{
     ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
 };
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::Numeric;
 use crate::core_crypto::prelude::{
-    CiphertextCount, GlweDimension, GlweSize, PlaintextCount, PolynomialSize,
+    CiphertextCount, GlweDimension, GlweSize, KeyKind, PlaintextCount, PolynomialSize, Variance,
 };
+#[cfg(feature = "__commons_parallel")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// A list of ciphertexts encoded with the GLWE scheme.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -21,6 +25,26 @@ pub struct GlweList<Cont> {
 
 tensor_traits!(GlweList);
 
+// The layout written by `GlweList::serialize_compact`.
+#[cfg(feature = "__commons_serialization")]
+#[derive(Serialize)]
+struct CompactGlweListRef<'a, Scalar> {
+    glwe_dimension: GlweDimension,
+    poly_size: PolynomialSize,
+    ciphertext_count: CiphertextCount,
+    data: &'a [Scalar],
+}
+
+// The layout read by `GlweList::deserialize_compact`.
+#[cfg(feature = "__commons_serialization")]
+#[derive(Deserialize)]
+struct CompactGlweList<Scalar> {
+    glwe_dimension: GlweDimension,
+    poly_size: PolynomialSize,
+    ciphertext_count: CiphertextCount,
+    data: Vec<Scalar>,
+}
+
 impl<Scalar> GlweList<Vec<Scalar>>
 where
     Scalar: Copy,
@@ -95,6 +119,60 @@ impl<Cont> GlweList<Cont> {
         }
     }
 
+    /// Creates a view of the ciphertexts stored at a fixed `stride` in a larger buffer.
+    ///
+    /// The ciphertext of index `i` is read from the `(glwe_dimension + 1) * poly_size` elements
+    /// starting at `i * stride`, and the elements in between are ignored. This avoids copying the
+    /// ciphertexts to a contiguous list when they are interleaved with other data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is smaller than the size of a ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// let contiguous = GlweList::from_container(
+    ///     (0..3 * 4 * 5).collect::<Vec<u32>>(),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(4),
+    /// );
+    /// // Each ciphertext is followed by a header of 2 elements.
+    /// let mut buffer = Vec::new();
+    /// for ciphertext in contiguous.ciphertext_iter() {
+    ///     buffer.extend_from_slice(ciphertext.into_container());
+    ///     buffer.extend_from_slice(&[u32::MAX, u32::MAX]);
+    /// }
+    /// let strided = GlweList::from_strided(buffer, PolynomialSize(4), GlweDimension(2), 14);
+    /// assert_eq!(strided.ciphertext_count(), CiphertextCount(5));
+    /// for (strided, contiguous) in strided.ciphertext_iter().zip(contiguous.ciphertext_iter()) {
+    ///     assert_eq!(strided, contiguous);
+    /// }
+    /// ```
+    pub fn from_strided(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        stride: usize,
+    ) -> StridedGlweList<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let size = glwe_dimension.to_glwe_size().0 * poly_size.0;
+        assert!(
+            stride >= size,
+            "The stride ({stride}) is smaller than the size of a ciphertext ({size})."
+        );
+        StridedGlweList {
+            tensor: Tensor::from_container(cont),
+            rlwe_size: glwe_dimension.to_glwe_size(),
+            poly_size,
+            stride,
+        }
+    }
+
     /// Returns the number of ciphertexts in the list.
     ///
     /// # Example
@@ -181,6 +259,92 @@ impl<Cont> GlweList<Cont> {
         GlweDimension(self.rlwe_size.0 - 1)
     }
 
+    /// Returns the ciphertext at the given index, borrowed from the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater or equal to the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// let mut list = GlweList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30),
+    /// );
+    /// list.ciphertext_iter_mut()
+    ///     .nth(4)
+    ///     .unwrap()
+    ///     .as_mut_tensor()
+    ///     .fill_with_element(5);
+    /// let ciphertext = list.get_ciphertext(4);
+    /// assert_eq!(ciphertext.polynomial_size(), PolynomialSize(10));
+    /// assert!(ciphertext.as_tensor().iter().all(|a| *a == 5));
+    /// ```
+    pub fn get_ciphertext(&self, index: usize) -> GlweCiphertext<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let count = self.ciphertext_count().0;
+        assert!(
+            index < count,
+            "Tried to access ciphertext {index} of a list containing {count} ciphertexts."
+        );
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        let sub = self.as_tensor().get_sub(index * size..(index + 1) * size);
+        GlweCiphertext::from_container(sub.into_container(), self.poly_size)
+    }
+
+    /// Returns the ciphertext at the given index, mutably borrowed from the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater or equal to the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// let mut list = GlweList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30),
+    /// );
+    /// let mut ciphertext = list.get_mut_ciphertext(4);
+    /// ciphertext.as_mut_tensor().fill_with_element(5);
+    /// for (i, ciphertext) in list.ciphertext_iter().enumerate() {
+    ///     let expected = if i == 4 { 5 } else { 0 };
+    ///     assert!(ciphertext.as_tensor().iter().all(|a| *a == expected));
+    /// }
+    /// ```
+    pub fn get_mut_ciphertext(
+        &mut self,
+        index: usize,
+    ) -> GlweCiphertext<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let count = self.ciphertext_count().0;
+        assert!(
+            index < count,
+            "Tried to access ciphertext {index} of a list containing {count} ciphertexts."
+        );
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        let poly_size = self.poly_size;
+        let sub = self
+            .as_mut_tensor()
+            .get_sub_mut(index * size..(index + 1) * size);
+        GlweCiphertext::from_container(sub.into_container(), poly_size)
+    }
+
     /// Returns an iterator over ciphertexts borrowed from the list.
     ///
     /// # Example
@@ -253,6 +417,209 @@ impl<Cont> GlweList<Cont> {
             .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
     }
 
+    /// Returns a parallel iterator over ciphertexts borrowed from the list.
+    ///
+    /// The ciphertexts are yielded in the same order as with
+    /// [`ciphertext_iter_mut`](Self::ciphertext_iter_mut).
+    ///
+    /// # Note
+    ///
+    /// This iterator is hidden behind the "__commons_parallel" feature gate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsMutTensor, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// let mut list = GlweList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30),
+    /// );
+    /// list.par_ciphertext_iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(i, mut ciphertext)| {
+    ///         ciphertext
+    ///             .get_mut_body()
+    ///             .as_mut_tensor()
+    ///             .fill_with_element(i as u8);
+    ///     });
+    /// for (i, ciphertext) in list.ciphertext_iter_mut().enumerate() {
+    ///     let body = ciphertext.get_body();
+    ///     assert!(body.as_tensor().iter().all(|a| *a == i as u8));
+    /// }
+    /// assert_eq!(list.par_ciphertext_iter_mut().count(), 30);
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_ciphertext_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = GlweCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+        <Self as AsMutTensor>::Element: Sync + Send,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.rlwe_size.0, self.poly_size.0);
+        let poly_size = self.poly_size;
+        let chunks_size = self.rlwe_size.0 * self.polynomial_size().0;
+        self.as_mut_tensor()
+            .par_subtensor_iter_mut(chunks_size)
+            .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
+    }
+
+    /// Returns an iterator decrypting the ciphertexts of the list one at a time.
+    ///
+    /// Every ciphertext is only decrypted when the iterator reaches it, which avoids decrypting
+    /// the whole list when only a few ciphertexts are inspected. Combine it with
+    /// [`Iterator::enumerate`] to recover the index of each ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key =
+    ///     GlweSecretKey::generate_binary(GlweDimension(2), PolynomialSize(4), &mut secret_generator);
+    /// let mut list = GlweList::allocate(
+    ///     0u32,
+    ///     PolynomialSize(4),
+    ///     GlweDimension(2),
+    ///     CiphertextCount(100),
+    /// );
+    /// list.fill_with_trivial_encryption(&PlaintextList::from_container(
+    ///     (0..400).collect::<Vec<u32>>(),
+    /// ));
+    /// for (index, decrypted) in list.decrypt_iter(&secret_key).enumerate().take(3) {
+    ///     let first = 4 * index as u32;
+    ///     assert_eq!(
+    ///         decrypted.as_tensor().as_slice(),
+    ///         &[first, first + 1, first + 2, first + 3]
+    ///     );
+    /// }
+    /// ```
+    pub fn decrypt_iter<'a, Kind, KeyCont, Scalar>(
+        &'a self,
+        secret_key: &'a GlweSecretKey<Kind, KeyCont>,
+    ) -> impl Iterator<Item = PlaintextList<Vec<Scalar>>> + 'a
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+    {
+        let plaintext_count = PlaintextCount(self.poly_size.0);
+        self.ciphertext_iter().map(move |ciphertext| {
+            let mut decrypted = PlaintextList::allocate(Scalar::ZERO, plaintext_count);
+            secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+            decrypted
+        })
+    }
+
+    /// Returns, for every ciphertext of the list, the empirical variance of its noise with respect
+    /// to the `expected` plaintexts.
+    ///
+    /// Each ciphertext is decrypted, and the variance is estimated as the mean of the squared
+    /// errors of its coefficients, expressed on the normalized torus. The noise is assumed to be
+    /// centered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `expected` does not hold one plaintext list of `poly_size` plaintexts per
+    /// ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::GlweSecretKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     CiphertextCount, DispersionParameter, GlweDimension, PolynomialSize, StandardDev,
+    /// };
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = StandardDev(2_f64.powi(-25));
+    /// let expected: Vec<_> = (0..4u64)
+    ///     .map(|i| PlaintextList::from_container(vec![i << 60; 1024]))
+    ///     .collect();
+    /// let mut list = GlweList::allocate(
+    ///     0u64,
+    ///     PolynomialSize(1024),
+    ///     GlweDimension(1),
+    ///     CiphertextCount(4),
+    /// );
+    /// for (mut ciphertext, plaintexts) in list.ciphertext_iter_mut().zip(expected.iter()) {
+    ///     secret_key.encrypt_glwe(
+    ///         &mut ciphertext,
+    ///         plaintexts,
+    ///         noise,
+    ///         &mut encryption_generator,
+    ///     );
+    /// }
+    /// for variance in list.measure_noise(&secret_key, &expected) {
+    ///     let ratio = variance.get_variance() / noise.get_variance();
+    ///     assert!(0.8 < ratio && ratio < 1.2, "{}", ratio);
+    /// }
+    /// ```
+    pub fn measure_noise<Kind, KeyCont, Scalar>(
+        &self,
+        secret_key: &GlweSecretKey<Kind, KeyCont>,
+        expected: &[PlaintextList<Vec<Scalar>>],
+    ) -> Vec<Variance>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+    {
+        assert_eq!(
+            expected.len(),
+            self.ciphertext_count().0,
+            "The number of expected plaintext lists must be equal to the number of ciphertexts."
+        );
+        self.decrypt_iter(secret_key)
+            .zip(expected.iter())
+            .map(|(decrypted, expected)| {
+                assert_eq!(
+                    expected.count().0,
+                    self.poly_size.0,
+                    "The number of expected plaintexts must be equal to the polynomial size."
+                );
+                let squared_errors = decrypted
+                    .plaintext_iter()
+                    .zip(expected.plaintext_iter())
+                    .map(|(decrypted, expected)| {
+                        let error: f64 = decrypted.0.wrapping_sub(expected.0).into_torus();
+                        // Maps the error from [0, 1[ to [-1/2, 1/2[
+                        let error = if error >= 0.5 { error - 1. } else { error };
+                        error * error
+                    })
+                    .sum::<f64>();
+                Variance(squared_errors / self.poly_size.0 as f64)
+            })
+            .collect()
+    }
+
     pub fn fill_with_trivial_encryption<PlaintextContainer, Scalar>(
         &mut self,
         plaintexts: &PlaintextList<PlaintextContainer>,
@@ -275,3 +642,112 @@ impl<Cont> GlweList<Cont> {
         }
     }
 }
+
+#[cfg(feature = "__commons_serialization")]
+impl<Cont> GlweList<Cont> {
+    /// Serializes the list in a compact form, made of the glwe dimension, the polynomial size, the
+    /// ciphertext count, and the raw elements of the list.
+    ///
+    /// The list can be read back with [`GlweList::deserialize_compact`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// let list = GlweList::from_container(
+    ///     (0..2 * 3 * 4).collect::<Vec<u64>>(),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(4),
+    /// );
+    /// let mut bytes = Vec::new();
+    /// list.serialize_compact(&mut bincode::Serializer::new(
+    ///     &mut bytes,
+    ///     bincode::DefaultOptions::new(),
+    /// ))
+    /// .unwrap();
+    /// let mut deserializer =
+    ///     bincode::Deserializer::from_slice(&bytes, bincode::DefaultOptions::new());
+    /// let deserialized = GlweList::<Vec<u64>>::deserialize_compact(&mut deserializer).unwrap();
+    /// assert_eq!(deserialized, list);
+    /// ```
+    pub fn serialize_compact<S, Scalar>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: Serialize,
+        S: Serializer,
+    {
+        CompactGlweListRef {
+            glwe_dimension: self.glwe_dimension(),
+            poly_size: self.poly_size,
+            ciphertext_count: self.ciphertext_count(),
+            data: self.as_tensor().as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "__commons_serialization")]
+impl<Scalar> GlweList<Vec<Scalar>> {
+    /// Deserializes a list written by [`GlweList::serialize_compact`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the polynomial size is zero, or if the number of serialized elements does not
+    /// match the declared glwe dimension, polynomial size and ciphertext count.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bincode::Options;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// // A payload declaring two ciphertexts, but holding the elements of a single one.
+    /// let payload = (
+    ///     GlweDimension(1),
+    ///     PolynomialSize(4),
+    ///     CiphertextCount(2),
+    ///     vec![0u64; 2 * 4],
+    /// );
+    /// let bytes = bincode::DefaultOptions::new().serialize(&payload).unwrap();
+    /// let mut deserializer =
+    ///     bincode::Deserializer::from_slice(&bytes, bincode::DefaultOptions::new());
+    /// let error = GlweList::<Vec<u64>>::deserialize_compact(&mut deserializer).unwrap_err();
+    /// assert!(error.to_string().contains("does not match"));
+    /// ```
+    pub fn deserialize_compact<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        Scalar: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let CompactGlweList {
+            glwe_dimension,
+            poly_size,
+            ciphertext_count,
+            data,
+        } = CompactGlweList::deserialize(deserializer)?;
+        if poly_size.0 == 0 {
+            return Err(serde::de::Error::custom(
+                "The polynomial size of a GLWE list can not be zero.",
+            ));
+        }
+        let expected_len = (glwe_dimension.0 + 1)
+            .checked_mul(poly_size.0)
+            .and_then(|len| len.checked_mul(ciphertext_count.0));
+        if expected_len != Some(data.len()) {
+            return Err(serde::de::Error::custom(format!(
+                "The serialized GLWE list holds {} elements, which does not match a glwe \
+                dimension of {}, a polynomial size of {} and {} ciphertexts.",
+                data.len(),
+                glwe_dimension.0,
+                poly_size.0,
+                ciphertext_count.0
+            )));
+        }
+        Ok(GlweList {
+            tensor: Tensor::from_container(data),
+            rlwe_size: glwe_dimension.to_glwe_size(),
+            poly_size,
+        })
+    }
+}
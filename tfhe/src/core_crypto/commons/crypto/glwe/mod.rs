@@ -2,16 +2,20 @@
 
 mod body;
 mod ciphertext;
+mod glwe_keyswitch;
 mod keyswitch;
 mod list;
 mod mask;
 mod seeded_ciphertext;
 mod seeded_list;
+mod strided_list;
 
 pub use body::*;
 pub use ciphertext::*;
+pub use glwe_keyswitch::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use mask::*;
 pub use seeded_ciphertext::*;
 pub use seeded_list::*;
+pub use strided_list::*;
@@ -0,0 +1,244 @@
+use super::GlweCiphertext;
+use crate::core_crypto::commons::math::tensor::{
+    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::core_crypto::prelude::{CiphertextCount, GlweDimension, GlweSize, PolynomialSize};
+
+/// A list of GLWE ciphertexts laid out at a fixed stride in a larger buffer.
+///
+/// The ciphertext of index `i` is stored in the `glwe_size * poly_size` elements starting at
+/// `i * stride`. The elements found between two ciphertexts are left untouched, which makes it
+/// possible to read ciphertexts interleaved with other data without copying them first.
+///
+/// See [`GlweList::from_strided`](`super::GlweList::from_strided`) for a constructor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StridedGlweList<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) rlwe_size: GlweSize,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) stride: usize,
+}
+
+tensor_traits!(StridedGlweList);
+
+impl<Cont> StridedGlweList<Cont> {
+    /// Returns the number of ciphertexts in the list.
+    ///
+    /// The last ciphertext does not need to be followed by a full stride.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// // The last ciphertext is not followed by any padding.
+    /// let buffer = vec![0u8; 2 * 12 + 9];
+    /// let list = GlweList::from_strided(buffer, PolynomialSize(3), GlweDimension(2), 12);
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(3));
+    /// ```
+    pub fn ciphertext_count(&self) -> CiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        let len = self.as_tensor().len();
+        if len < size {
+            CiphertextCount(0)
+        } else {
+            CiphertextCount((len - size) / self.stride + 1)
+        }
+    }
+
+    /// Returns the size of the glwe ciphertexts contained in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, GlweSize, PolynomialSize};
+    /// let list = GlweList::from_strided(vec![0u8; 36], PolynomialSize(3), GlweDimension(2), 12);
+    /// assert_eq!(list.glwe_size(), GlweSize(3));
+    /// ```
+    pub fn glwe_size(&self) -> GlweSize {
+        self.rlwe_size
+    }
+
+    /// Returns the number of masks of the glwe ciphertexts contained in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let list = GlweList::from_strided(vec![0u8; 36], PolynomialSize(3), GlweDimension(2), 12);
+    /// assert_eq!(list.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.rlwe_size.0 - 1)
+    }
+
+    /// Returns the number of coefficients of the polynomials used for the list ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let list = GlweList::from_strided(vec![0u8; 36], PolynomialSize(3), GlweDimension(2), 12);
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(3));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of elements separating the beginnings of two consecutive ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let list = GlweList::from_strided(vec![0u8; 36], PolynomialSize(3), GlweDimension(2), 12);
+    /// assert_eq!(list.stride(), 12);
+    /// ```
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the ciphertext at the given index, borrowed from the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater or equal to the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let buffer: Vec<u8> = (0..24).collect();
+    /// let list = GlweList::from_strided(buffer, PolynomialSize(2), GlweDimension(1), 8);
+    /// let ciphertext = list.get_ciphertext(2);
+    /// assert_eq!(ciphertext.as_tensor().as_slice(), &[16, 17, 18, 19]);
+    /// ```
+    pub fn get_ciphertext(&self, index: usize) -> GlweCiphertext<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let count = self.ciphertext_count().0;
+        assert!(
+            index < count,
+            "Tried to access ciphertext {index} of a list containing {count} ciphertexts."
+        );
+        let start = index * self.stride;
+        let sub = self
+            .as_tensor()
+            .get_sub(start..start + self.rlwe_size.0 * self.poly_size.0);
+        GlweCiphertext::from_container(sub.into_container(), self.poly_size)
+    }
+
+    /// Returns the ciphertext at the given index, mutably borrowed from the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater or equal to the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let buffer = vec![0u8; 14];
+    /// let mut list = GlweList::from_strided(buffer, PolynomialSize(2), GlweDimension(1), 5);
+    /// list.get_mut_ciphertext(1)
+    ///     .as_mut_tensor()
+    ///     .fill_with_element(7);
+    /// assert_eq!(
+    ///     list.as_tensor().as_slice(),
+    ///     &[0, 0, 0, 0, 0, 7, 7, 7, 7, 0, 0, 0, 0, 0]
+    /// );
+    /// ```
+    pub fn get_mut_ciphertext(
+        &mut self,
+        index: usize,
+    ) -> GlweCiphertext<&mut [<Self as AsMutTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        let count = self.ciphertext_count().0;
+        assert!(
+            index < count,
+            "Tried to access ciphertext {index} of a list containing {count} ciphertexts."
+        );
+        let start = index * self.stride;
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        let poly_size = self.poly_size;
+        let sub = self.as_mut_tensor().get_sub_mut(start..start + size);
+        GlweCiphertext::from_container(sub.into_container(), poly_size)
+    }
+
+    /// Returns an iterator over the ciphertexts borrowed from the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let list = GlweList::from_strided(vec![0u8; 36], PolynomialSize(3), GlweDimension(2), 12);
+    /// for ciphertext in list.ciphertext_iter() {
+    ///     assert_eq!(ciphertext.polynomial_size(), PolynomialSize(3));
+    /// }
+    /// assert_eq!(list.ciphertext_iter().count(), 3);
+    /// ```
+    pub fn ciphertext_iter(
+        &self,
+    ) -> impl Iterator<Item = GlweCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let poly_size = self.poly_size;
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        self.as_tensor()
+            .as_slice()
+            .chunks(self.stride)
+            .take(self.ciphertext_count().0)
+            .map(move |chunk| GlweCiphertext::from_container(&chunk[..size], poly_size))
+    }
+
+    /// Returns an iterator over the ciphertexts mutably borrowed from the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{GlweDimension, PolynomialSize};
+    /// let buffer = vec![0u8; 14];
+    /// let mut list = GlweList::from_strided(buffer, PolynomialSize(2), GlweDimension(1), 5);
+    /// for mut ciphertext in list.ciphertext_iter_mut() {
+    ///     ciphertext.as_mut_tensor().fill_with_element(7);
+    /// }
+    /// assert_eq!(
+    ///     list.as_tensor().as_slice(),
+    ///     &[7, 7, 7, 7, 0, 7, 7, 7, 7, 0, 7, 7, 7, 7]
+    /// );
+    /// ```
+    pub fn ciphertext_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let poly_size = self.poly_size;
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        let count = self.ciphertext_count().0;
+        let stride = self.stride;
+        self.as_mut_tensor()
+            .as_mut_slice()
+            .chunks_mut(stride)
+            .take(count)
+            .map(move |chunk| GlweCiphertext::from_container(&mut chunk[..size], poly_size))
+    }
+}
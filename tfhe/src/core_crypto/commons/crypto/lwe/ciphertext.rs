@@ -52,6 +52,25 @@ where
         ciphertext.fill_with_trivial_encryption(plaintext);
         ciphertext
     }
+
+    /// Allocates a new ciphertext containing the trivial encryption of zero.
+    ///
+    /// This is the canonical way to allocate the output buffer of operations such as
+    /// keyswitches or sample extractions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCiphertext;
+    /// use tfhe::core_crypto::commons::math::tensor::AsRefTensor;
+    /// use tfhe::core_crypto::prelude::LweSize;
+    /// let ct = LweCiphertext::<Vec<u64>>::allocate_trivial_zero(LweSize(4));
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// assert!(ct.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn allocate_trivial_zero(lwe_size: LweSize) -> Self {
+        Self::allocate(Scalar::ZERO, lwe_size)
+    }
 }
 
 impl<Cont> LweCiphertext<Cont> {
@@ -281,23 +300,72 @@ impl<Cont> LweCiphertext<Cont> {
     }
 
     /// Adds the `other` ciphertext to the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two ciphertexts have different sizes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Cleartext, Plaintext};
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::LweSecretKey;
+    /// use tfhe::core_crypto::prelude::{LogStandardDev, LweDimension};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: LweSecretKey<_, Vec<u64>> =
+    ///     LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let mut ciphertext = LweCiphertext::allocate(0u64, LweDimension(256).to_lwe_size());
+    /// let mut other = ciphertext.clone();
+    /// secret_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &Plaintext(1 << 60),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// secret_key.encrypt_lwe(
+    ///     &mut other,
+    ///     &Plaintext(2 << 60),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// ciphertext.update_with_add(&other);
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let error = decrypted.0.wrapping_sub(3 << 60);
+    /// assert!(error.min(error.wrapping_neg()) < 1 << 30);
+    /// ```
     pub fn update_with_add<OtherCont, Scalar>(&mut self, other: &LweCiphertext<OtherCont>)
     where
         Self: AsMutTensor<Element = Scalar>,
         LweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        self.check_same_size(other);
         self.as_mut_tensor()
             .update_with_wrapping_add(other.as_tensor())
     }
 
     /// Subtracts the `other` ciphertext from the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two ciphertexts have different sizes.
     pub fn update_with_sub<OtherCont, Scalar>(&mut self, other: &LweCiphertext<OtherCont>)
     where
         Self: AsMutTensor<Element = Scalar>,
         LweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        self.check_same_size(other);
         self.as_mut_tensor()
             .update_with_wrapping_sub(other.as_tensor())
     }
@@ -312,6 +380,42 @@ impl<Cont> LweCiphertext<Cont> {
     }
 
     /// Multiplies the current ciphertext with a scalar value inplace.
+    ///
+    /// # Note
+    ///
+    /// The noise of the ciphertext is multiplied by the same scalar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Cleartext, Plaintext};
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::LweSecretKey;
+    /// use tfhe::core_crypto::prelude::{LogStandardDev, LweDimension};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: LweSecretKey<_, Vec<u64>> =
+    ///     LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let mut ciphertext = LweCiphertext::allocate(0u64, LweDimension(256).to_lwe_size());
+    /// secret_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &Plaintext(3 << 58),
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// ciphertext.update_with_scalar_mul(Cleartext(2));
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let error = decrypted.0.wrapping_sub(6 << 58);
+    /// assert!(error.min(error.wrapping_neg()) < 1 << 30);
+    /// ```
     pub fn update_with_scalar_mul<Scalar>(&mut self, scalar: Cleartext<Scalar>)
     where
         Self: AsMutTensor<Element = Scalar>,
@@ -403,6 +507,18 @@ impl<Cont> LweCiphertext<Cont> {
         // add the encoded message
         output_body.0 = plaintext.0;
     }
+
+    fn check_same_size<OtherCont>(&self, other: &LweCiphertext<OtherCont>)
+    where
+        Self: AsRefTensor,
+        LweCiphertext<OtherCont>: AsRefTensor,
+    {
+        assert_eq!(
+            self.lwe_size(),
+            other.lwe_size(),
+            "The two LWE ciphertexts have different sizes."
+        );
+    }
 }
 
 /// The mask of an LWE encrypted ciphertext.
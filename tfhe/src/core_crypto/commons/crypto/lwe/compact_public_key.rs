@@ -0,0 +1,317 @@
+use super::LweCiphertext;
+use crate::core_crypto::commons::crypto::encoding::Plaintext;
+use crate::core_crypto::commons::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::core_crypto::commons::crypto::secret::LweSecretKey;
+use crate::core_crypto::commons::math::polynomial::Polynomial;
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
+use crate::core_crypto::commons::math::tensor::{
+    tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::prelude::{BinaryKeyKind, DispersionParameter, LweDimension};
+#[cfg(feature = "__commons_serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A compact LWE public key.
+///
+/// Where an [`LweList`](`super::LweList`) public key stores many LWE encryptions of zero, a
+/// compact public key stores a single encryption of zero in the ring
+/// $\mathbb{Z}_q\[X\]/(X^n+1)$, where $n$ is the LWE dimension: a uniformly random mask polynomial
+/// $A$ and a body polynomial $B = A \cdot S + E$, with $S$ the polynomial whose coefficients are
+/// the secret key ones. Its size is $2n$ elements, regardless of the number of encryptions it is
+/// used for.
+///
+/// The ring $\mathbb{Z}_q\[X\]/(X^n+1)$ is only cyclotomic when $n$ is a power of two, which is
+/// what the security of the scheme relies on.
+#[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompactPublicKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+}
+
+tensor_traits!(LweCompactPublicKey);
+
+impl<Scalar> LweCompactPublicKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a compact public key whose mask and body coefficients all have the value
+    /// `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+    /// use tfhe::core_crypto::prelude::LweDimension;
+    /// let public_key = LweCompactPublicKey::allocate(0u64, LweDimension(256));
+    /// assert_eq!(public_key.lwe_dimension(), LweDimension(256));
+    /// ```
+    pub fn allocate(value: Scalar, lwe_dimension: LweDimension) -> Self {
+        LweCompactPublicKey {
+            tensor: Tensor::from_container(vec![value; 2 * lwe_dimension.0]),
+        }
+    }
+}
+
+impl<Cont> LweCompactPublicKey<Cont> {
+    /// Creates a compact public key from a container holding the mask coefficients followed by
+    /// the body coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the container is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+    /// use tfhe::core_crypto::prelude::LweDimension;
+    /// let public_key = LweCompactPublicKey::from_container(vec![0u64; 512]);
+    /// assert_eq!(public_key.lwe_dimension(), LweDimension(256));
+    /// ```
+    pub fn from_container(cont: Cont) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        assert!(
+            tensor.len() % 2 == 0,
+            "A compact public key must contain as many mask as body coefficients, got {} \
+            coefficients.",
+            tensor.len()
+        );
+        LweCompactPublicKey { tensor }
+    }
+
+    /// Returns the LWE dimension of the ciphertexts encrypted with the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+    /// use tfhe::core_crypto::prelude::LweDimension;
+    /// let public_key = LweCompactPublicKey::allocate(0u64, LweDimension(256));
+    /// assert_eq!(public_key.lwe_dimension(), LweDimension(256));
+    /// ```
+    pub fn lwe_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(self.as_tensor().len() / 2)
+    }
+
+    /// Returns borrowed polynomials containing the mask and the body of the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+    /// use tfhe::core_crypto::prelude::{LweDimension, PolynomialSize};
+    /// let public_key = LweCompactPublicKey::allocate(0u64, LweDimension(256));
+    /// let (mask, body) = public_key.get_mask_and_body();
+    /// assert_eq!(mask.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(body.polynomial_size(), PolynomialSize(256));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn get_mask_and_body(
+        &self,
+    ) -> (
+        Polynomial<&[<Self as AsRefTensor>::Element]>,
+        Polynomial<&[<Self as AsRefTensor>::Element]>,
+    )
+    where
+        Self: AsRefTensor,
+    {
+        let (mask, body) = self.as_tensor().as_slice().split_at(self.lwe_dimension().0);
+        (
+            Polynomial::from_container(mask),
+            Polynomial::from_container(body),
+        )
+    }
+
+    /// Returns mutably borrowed polynomials containing the mask and the body of the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::LweDimension;
+    /// let mut public_key = LweCompactPublicKey::allocate(0u64, LweDimension(2));
+    /// let (mut mask, mut body) = public_key.get_mut_mask_and_body();
+    /// mask.as_mut_tensor().fill_with_element(1);
+    /// body.as_mut_tensor().fill_with_element(2);
+    /// assert_eq!(public_key.as_tensor().as_slice(), &[1, 1, 2, 2]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn get_mut_mask_and_body(
+        &mut self,
+    ) -> (
+        Polynomial<&mut [<Self as AsRefTensor>::Element]>,
+        Polynomial<&mut [<Self as AsRefTensor>::Element]>,
+    )
+    where
+        Self: AsMutTensor,
+    {
+        let lwe_dimension = self.lwe_dimension().0;
+        let (mask, body) = self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .split_at_mut(lwe_dimension);
+        (
+            Polynomial::from_container(mask),
+            Polynomial::from_container(body),
+        )
+    }
+
+    /// Fills the key with a fresh encryption of zero under the secret key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the secret key and the public key have different LWE dimensions, or if this
+    /// dimension is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// See [`LweCompactPublicKey::encrypt_lwe`] for an example.
+    pub fn fill_with_compact_public_key<KeyCont, Scalar, Gen>(
+        &mut self,
+        secret_key: &LweSecretKey<BinaryKeyKind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        assert_eq!(
+            self.lwe_dimension(),
+            secret_key.key_size(),
+            "The secret key and the compact public key have different LWE dimensions."
+        );
+        assert!(
+            secret_key.key_size().0.is_power_of_two(),
+            "The LWE dimension of a compact public key must be a power of two, got {}.",
+            secret_key.key_size().0
+        );
+        let key_polynomial = Polynomial::from_container(secret_key.as_tensor().as_slice());
+        let (mut mask, mut body) = self.get_mut_mask_and_body();
+        generator.fill_tensor_with_random_mask(&mut mask);
+        generator.fill_tensor_with_random_noise(&mut body, noise_parameters);
+        body.update_with_wrapping_add_mul(&mask, &key_polynomial);
+    }
+
+    /// Encrypts a plaintext into an LWE ciphertext, using the public key.
+    ///
+    /// A random binary polynomial $U$ and random errors $E_1$ and $e_2$ are drawn. The ciphertext
+    /// mask is obtained from the polynomial $A \cdot U + E_1$, and its body from the constant
+    /// coefficient of $B \cdot U$, shifted by $e_2$ and by the plaintext. The mask coefficients
+    /// are laid out so that the ciphertext decrypts under the LWE secret key used to generate the
+    /// public key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output ciphertext and the public key have different LWE dimensions, or if
+    /// this dimension is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::Plaintext;
+    /// use tfhe::core_crypto::commons::crypto::lwe::{LweCiphertext, LweCompactPublicKey};
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::LweSecretKey;
+    /// use tfhe::core_crypto::prelude::{LogStandardDev, LweDimension};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    ///
+    /// let mut public_key = LweCompactPublicKey::allocate(0u64, LweDimension(256));
+    /// public_key.fill_with_compact_public_key(&secret_key, noise, &mut encryption_generator);
+    ///
+    /// let plaintext = Plaintext(3u64 << 60);
+    /// let mut ciphertext = LweCiphertext::allocate(0u64, LweDimension(256).to_lwe_size());
+    /// public_key.encrypt_lwe(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     noise,
+    ///     &mut secret_generator,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let error = decrypted.0.wrapping_sub(plaintext.0);
+    /// assert!(error.min(error.wrapping_neg()) < 1 << 40);
+    /// ```
+    pub fn encrypt_lwe<OutputCont, Scalar, SecretGen, EncryptionGen>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        secret_generator: &mut SecretRandomGenerator<SecretGen>,
+        encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        SecretGen: ByteRandomGenerator,
+        EncryptionGen: ByteRandomGenerator,
+    {
+        let lwe_dimension = self.lwe_dimension().0;
+        assert_eq!(
+            output.lwe_size().to_lwe_dimension().0,
+            lwe_dimension,
+            "The output ciphertext and the compact public key have different LWE dimensions."
+        );
+        assert!(
+            lwe_dimension.is_power_of_two(),
+            "The LWE dimension of a compact public key must be a power of two, got {lwe_dimension}."
+        );
+        let (pk_mask, pk_body) = self.get_mask_and_body();
+        let binary_random = Polynomial::from_container(
+            secret_generator
+                .random_binary_tensor::<Scalar>(lwe_dimension)
+                .into_container(),
+        );
+
+        // The constant coefficient of A * U + E1 is the first mask coefficient, and the others are
+        // the opposites of the remaining coefficients in reverse order, so that the multisum of the
+        // mask with the secret key is the constant coefficient of (A * U + E1) * S.
+        let mut product = Polynomial::from_container(vec![Scalar::ZERO; lwe_dimension]);
+        encryption_generator.fill_tensor_with_random_noise(&mut product, noise_parameters);
+        product.update_with_wrapping_add_mul(&pk_mask, &binary_random);
+        let (body, mut mask) = output.get_mut_body_and_mask();
+        let product = product.as_tensor().as_slice();
+        let mask = mask.as_mut_tensor().as_mut_slice();
+        mask[0] = product[0];
+        for (mask_coef, product_coef) in mask[1..].iter_mut().zip(product[1..].iter().rev()) {
+            *mask_coef = product_coef.wrapping_neg();
+        }
+
+        // The constant coefficient of B * U.
+        let pk_body = pk_body.as_tensor().as_slice();
+        let binary_random = binary_random.as_tensor().as_slice();
+        let constant = pk_body[1..]
+            .iter()
+            .zip(binary_random[1..].iter().rev())
+            .fold(
+                pk_body[0].wrapping_mul(binary_random[0]),
+                |acc, (body_coef, random_coef)| {
+                    acc.wrapping_sub(body_coef.wrapping_mul(*random_coef))
+                },
+            );
+        body.0 = constant
+            .wrapping_add(encryption_generator.random_noise(noise_parameters))
+            .wrapping_add(encoded.0);
+    }
+}
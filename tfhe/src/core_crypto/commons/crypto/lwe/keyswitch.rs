@@ -94,6 +94,72 @@ where
     }
 }
 
+impl<Scalar> LweKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Allocates a keyswitching key and fills it with an actual keyswitching key from
+    /// `input_key` to `output_key`.
+    ///
+    /// This is equivalent to an [`LweKeyswitchKey::allocate`] followed by
+    /// [`LweKeyswitchKey::fill_with_keyswitch_key`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweKeyswitchKey;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::LweSecretKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LogStandardDev, LweDimension,
+    /// };
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let input_key = LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = LweSecretKey::generate_binary(LweDimension(20), &mut secret_generator);
+    ///
+    /// let ksk: LweKeyswitchKey<Vec<u32>> = LweKeyswitchKey::new(
+    ///     &input_key,
+    ///     &output_key,
+    ///     DecompositionBaseLog(3),
+    ///     DecompositionLevelCount(5),
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// assert_eq!(ksk.before_key_size(), LweDimension(10));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn new<InKeyCont, OutKeyCont, Gen>(
+        input_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
+        output_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) -> Self
+    where
+        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Gen: ByteRandomGenerator,
+    {
+        let mut ksk = LweKeyswitchKey::allocate(
+            Scalar::ZERO,
+            decomp_level_count,
+            decomp_base_log,
+            input_key.key_size(),
+            output_key.key_size(),
+        );
+        ksk.fill_with_keyswitch_key(input_key, output_key, noise_parameters, generator);
+        ksk
+    }
+}
+
 impl<Cont> LweKeyswitchKey<Cont> {
     /// Creates an LWE key switching key from a container.
     ///
@@ -480,7 +546,16 @@ impl<Cont> LweKeyswitchKey<Cont> {
             .map(move |sub| LweKeyBitDecomposition::from_container(sub.into_container(), lwe_size))
     }
 
-    /// Switches the key of a signel Lwe ciphertext.
+    /// Switches the key of a single Lwe ciphertext.
+    ///
+    /// The mask of `before` is decomposed, and the matching encryptions of the input key are
+    /// subtracted from the body of `before`, so that `after` encrypts the same plaintext under the
+    /// output key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask size of `before` does not match the input key size, or if the mask size
+    /// of `after` does not match the output key size.
     ///
     /// # Example
     ///
@@ -503,7 +578,7 @@ impl<Cont> LweKeyswitchKey<Cont> {
     /// let output_size = LweDimension(1024);
     /// let decomp_log_base = DecompositionBaseLog(3);
     /// let decomp_level_count = DecompositionLevelCount(8);
-    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
     /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
     /// let mut encryption_generator =
     ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
@@ -519,7 +594,7 @@ impl<Cont> LweKeyswitchKey<Cont> {
     /// );
     /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut encryption_generator);
     ///
-    /// let plaintext: Plaintext<u64> = Plaintext(1432154329994324);
+    /// let plaintext: Plaintext<u64> = Plaintext(3 << 60);
     /// let mut ciphertext = LweCiphertext::allocate(0. as u64, LweSize(1025));
     /// let mut switched_ciphertext = LweCiphertext::allocate(0. as u64, LweSize(1025));
     /// input_key.encrypt_lwe(
@@ -533,6 +608,8 @@ impl<Cont> LweKeyswitchKey<Cont> {
     ///
     /// let mut decrypted = Plaintext(0 as u64);
     /// output_key.decrypt_lwe(&mut decrypted, &switched_ciphertext);
+    /// let error = decrypted.0.wrapping_sub(plaintext.0);
+    /// assert!(error.min(error.wrapping_neg()) < 1 << 50);
     /// ```
     pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
         &self,
@@ -544,8 +621,17 @@ impl<Cont> LweKeyswitchKey<Cont> {
         LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
-        ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+        assert_eq!(
+            self.before_key_size(),
+            before.get_mask().mask_size(),
+            "The input ciphertext dimension does not match the input key size of the keyswitch key."
+        );
+        assert_eq!(
+            self.after_key_size(),
+            after.get_mask().mask_size(),
+            "The output ciphertext dimension does not match the output key size of the keyswitch \
+            key."
+        );
 
         // We reset the output
         after.as_mut_tensor().fill_with(|| Scalar::ZERO);
@@ -1,7 +1,9 @@
 use super::LweCiphertext;
-use crate::core_crypto::commons::crypto::encoding::{CleartextList, PlaintextList};
+use crate::core_crypto::commons::crypto::encoding::{CleartextList, Plaintext, PlaintextList};
+use crate::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
 use crate::core_crypto::commons::math::tensor::{
-    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
+    ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Container, Tensor,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::utils::{zip, zip_args};
@@ -10,6 +12,7 @@ use crate::core_crypto::prelude::{CiphertextCount, CleartextCount, LweDimension,
 use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
 /// A list of ciphertext encoded with the LWE scheme.
 #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
@@ -43,6 +46,44 @@ where
             lwe_size,
         }
     }
+
+    /// Concatenates several lists into a new list, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lists` is empty, or if the lists do not all have the same lwe size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweList;
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, LweSize};
+    /// let first = LweList::allocate(1 as u8, LweSize(10), CiphertextCount(2));
+    /// let second = LweList::allocate(2 as u8, LweSize(10), CiphertextCount(3));
+    /// let list = LweList::concat(&[&first, &second]);
+    /// assert_eq!(list.count(), CiphertextCount(5));
+    /// assert_eq!(list.lwe_size(), LweSize(10));
+    /// assert_eq!(list.sublist(2..5), second.as_view());
+    /// ```
+    pub fn concat<Cont>(lists: &[&LweList<Cont>]) -> Self
+    where
+        LweList<Cont>: AsRefTensor<Element = Scalar>,
+    {
+        assert!(
+            !lists.is_empty(),
+            "Tried to concatenate an empty slice of lists"
+        );
+        let lwe_size = lists[0].lwe_size;
+        let mut container = Vec::new();
+        for list in lists {
+            assert_eq!(
+                list.lwe_size, lwe_size,
+                "Tried to concatenate lists with different lwe sizes"
+            );
+            container.extend_from_slice(list.as_tensor().as_slice());
+        }
+        LweList::from_container(container, lwe_size)
+    }
 }
 
 impl<Scalar> LweList<Vec<Scalar>>
@@ -265,6 +306,39 @@ impl<Cont> LweList<Cont> {
             .map(|sub| LweCiphertext::from_container(sub.into_container()))
     }
 
+    /// Returns a sub list borrowing the ciphertexts of indices in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range goes past the number of ciphertexts of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweList;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{CiphertextCount, LweSize};
+    /// let list = LweList::from_container((0..20 as u8).collect::<Vec<_>>(), LweSize(4));
+    /// let sublist = list.sublist(1..3);
+    /// assert_eq!(sublist.count(), CiphertextCount(2));
+    /// assert_eq!(sublist.as_tensor().as_slice(), &[4, 5, 6, 7, 8, 9, 10, 11]);
+    /// ```
+    pub fn sublist(&self, range: Range<usize>) -> LweList<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        let count = self.count().0;
+        assert!(
+            range.start <= range.end && range.end <= count,
+            "Tried to take the ciphertexts {range:?} of a list containing {count} ciphertexts."
+        );
+        let lwe_size = self.lwe_size.0;
+        let sub = self
+            .as_tensor()
+            .get_sub(range.start * lwe_size..range.end * lwe_size);
+        LweList::from_container(sub.into_container(), self.lwe_size)
+    }
+
     /// Returns an iterator over sub lists borrowed from the list.
     ///
     /// # Example
@@ -395,4 +469,96 @@ impl<Cont> LweList<Cont> {
             cipher.fill_with_trivial_encryption(plaintext);
         }
     }
+
+    /// Encrypts a plaintext using the list as an LWE public key.
+    ///
+    /// The list is expected to contain encryptions of zero. A random subset of the first
+    /// `zero_encryption_count` ciphertexts of the list is summed into `output`, whose body is then
+    /// shifted by the plaintext. Using more encryptions of zero makes the random subset harder to
+    /// guess, at the price of a larger noise in the output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zero_encryption_count` is larger than the number of ciphertexts in the list, or
+    /// if `output` and the list have different LWE sizes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::{Plaintext, PlaintextList};
+    /// use tfhe::core_crypto::commons::crypto::lwe::{LweCiphertext, LweList};
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::LweSecretKey;
+    /// use tfhe::core_crypto::prelude::{
+    ///     CiphertextCount, LogStandardDev, LweDimension, PlaintextCount,
+    /// };
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    ///
+    /// // The public key is a list of encryptions of zero.
+    /// let mut public_key =
+    ///     LweList::allocate(0u64, LweDimension(256).to_lwe_size(), CiphertextCount(64));
+    /// secret_key.encrypt_lwe_list(
+    ///     &mut public_key,
+    ///     &PlaintextList::allocate(0u64, PlaintextCount(64)),
+    ///     LogStandardDev::from_log_standard_dev(-50.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let plaintext = Plaintext(3u64 << 60);
+    /// let mut ciphertext = LweCiphertext::allocate(0u64, LweDimension(256).to_lwe_size());
+    /// public_key.encrypt_lwe_with_public_key(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     CiphertextCount(64),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let error = decrypted.0.wrapping_sub(plaintext.0);
+    /// assert!(error.min(error.wrapping_neg()) < 1 << 40);
+    /// ```
+    pub fn encrypt_lwe_with_public_key<OutputCont, Scalar, Gen>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+        plaintext: &Plaintext<Scalar>,
+        zero_encryption_count: CiphertextCount,
+        generator: &mut SecretRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        OutputCont: AsMutSlice<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        assert!(
+            zero_encryption_count.0 <= self.count().0,
+            "Tried to sum {} encryptions of zero out of a public key containing {}.",
+            zero_encryption_count.0,
+            self.count().0
+        );
+        assert_eq!(
+            output.lwe_size(),
+            self.lwe_size(),
+            "The output ciphertext and the public key have different LWE sizes."
+        );
+        // Fills output masks with zeros, store input in the body
+        output.fill_with_trivial_encryption(plaintext);
+        let ct_choice = generator.random_binary_tensor::<Scalar>(zero_encryption_count.0);
+
+        // Add the public encryption of zeros to get the encryption
+        for (&chosen, public_encryption_of_zero) in
+            ct_choice.as_container().iter().zip(self.ciphertext_iter())
+        {
+            if chosen == Scalar::ONE {
+                output.update_with_add(&public_encryption_of_zero);
+            }
+        }
+    }
 }
@@ -1,5 +1,6 @@
 //! LWE encryption scheme.
 mod ciphertext;
+mod compact_public_key;
 mod keyswitch;
 mod list;
 mod seeded_ciphertext;
@@ -7,6 +8,7 @@ mod seeded_keyswitch;
 mod seeded_list;
 
 pub use ciphertext::*;
+pub use compact_public_key::*;
 pub use keyswitch::*;
 pub use list::*;
 pub use seeded_ciphertext::*;
@@ -15,16 +17,22 @@ pub use seeded_list::*;
 
 #[cfg(test)]
 mod test {
-    use crate::core_crypto::commons::crypto::lwe::{LweKeyswitchKey, LweSeededKeyswitchKey};
+    use crate::core_crypto::commons::crypto::encoding::{Plaintext, PlaintextList};
+    use crate::core_crypto::commons::crypto::lwe::{
+        LweCiphertext, LweKeyswitchKey, LweList, LweSeededKeyswitchKey,
+    };
     use crate::core_crypto::commons::crypto::secret::generators::{
         DeterministicSeeder, EncryptionRandomGenerator,
     };
     use crate::core_crypto::commons::crypto::secret::LweSecretKey;
     use crate::core_crypto::commons::math::random::CompressionSeed;
     use crate::core_crypto::commons::math::torus::UnsignedTorus;
-    use crate::core_crypto::commons::test_tools::new_secret_random_generator;
+    use crate::core_crypto::commons::test_tools::{
+        new_encryption_random_generator, new_secret_random_generator,
+    };
     use crate::core_crypto::prelude::{
-        DecompositionBaseLog, DecompositionLevelCount, LweDimension, StandardDev,
+        CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LogStandardDev,
+        LweDimension, PlaintextCount, StandardDev,
     };
     use concrete_csprng::generators::SoftwareRandomGenerator;
     use concrete_csprng::seeders::Seed;
@@ -103,4 +111,193 @@ mod test {
     fn test_ksk_seeded_gen_equivalence_u64() {
         test_ksk_seeded_gen_equivalence::<u64>()
     }
+
+    fn test_ksk_new_equivalence<T: UnsignedTorus>() {
+        use crate::core_crypto::commons::test_tools::random_usize_between;
+        use crate::core_crypto::prelude::LweSize;
+
+        let input_lwe_dim = LweDimension(random_usize_between(10..100));
+        let output_lwe_dim = LweDimension(random_usize_between(10..100));
+        let level = DecompositionLevelCount(5);
+        let base_log = DecompositionBaseLog(4);
+        let noise = LogStandardDev::from_log_standard_dev(-25.);
+        let mask_seed = Seed(crate::core_crypto::commons::test_tools::any_usize() as u128);
+        let deterministic_seeder_seed =
+            Seed(crate::core_crypto::commons::test_tools::any_usize() as u128);
+        let new_generator = || {
+            EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+                mask_seed,
+                &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(deterministic_seeder_seed),
+            )
+        };
+
+        let mut secret_generator = new_secret_random_generator();
+        let input_key = LweSecretKey::generate_binary(input_lwe_dim, &mut secret_generator);
+        let output_key = LweSecretKey::generate_binary(output_lwe_dim, &mut secret_generator);
+
+        let ksk = LweKeyswitchKey::new(
+            &input_key,
+            &output_key,
+            base_log,
+            level,
+            noise,
+            &mut new_generator(),
+        );
+
+        let mut two_step_ksk =
+            LweKeyswitchKey::allocate(T::ZERO, level, base_log, input_lwe_dim, output_lwe_dim);
+        two_step_ksk.fill_with_keyswitch_key(&input_key, &output_key, noise, &mut new_generator());
+        assert_eq!(ksk, two_step_ksk);
+
+        let mut encryption_generator = new_encryption_random_generator();
+        let plaintext = Plaintext(T::ONE << (T::BITS - 2));
+        let mut ciphertext = LweCiphertext::allocate(T::ZERO, input_lwe_dim.to_lwe_size());
+        input_key.encrypt_lwe(
+            &mut ciphertext,
+            &plaintext,
+            noise,
+            &mut encryption_generator,
+        );
+        let mut switched = LweCiphertext::allocate(T::ZERO, LweSize(output_lwe_dim.0 + 1));
+        ksk.keyswitch_ciphertext(&mut switched, &ciphertext);
+
+        let mut decrypted = Plaintext(T::ZERO);
+        output_key.decrypt_lwe(&mut decrypted, &switched);
+        let error = decrypted.0.wrapping_sub(plaintext.0);
+        assert!(error.min(error.wrapping_neg()) < T::ONE << (T::BITS - 10));
+    }
+
+    #[test]
+    fn test_ksk_new_equivalence_u32() {
+        test_ksk_new_equivalence::<u32>()
+    }
+
+    #[test]
+    fn test_ksk_new_equivalence_u64() {
+        test_ksk_new_equivalence::<u64>()
+    }
+
+    fn test_public_key_encryption_decrypts<T: UnsignedTorus>() {
+        let lwe_dim =
+            LweDimension(crate::core_crypto::commons::test_tools::random_usize_between(10..100));
+        let zero_encryption_count =
+            CiphertextCount(crate::core_crypto::commons::test_tools::random_usize_between(10..100));
+        let mut secret_generator = new_secret_random_generator();
+        let mut encryption_generator = new_encryption_random_generator();
+        let secret_key = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+
+        let mut public_key =
+            LweList::allocate(T::ZERO, lwe_dim.to_lwe_size(), zero_encryption_count);
+        secret_key.encrypt_lwe_list(
+            &mut public_key,
+            &PlaintextList::allocate(T::ZERO, PlaintextCount(zero_encryption_count.0)),
+            LogStandardDev::from_log_standard_dev(-25.),
+            &mut encryption_generator,
+        );
+
+        // The messages are encoded in the 4 most significant bits, and each encryption of zero
+        // adds much less noise than the 2^(-5) decoding margin.
+        for _ in 0..1000 {
+            let message =
+                crate::core_crypto::commons::test_tools::random_uint_between(T::ZERO..T::ONE << 4);
+            let plaintext = Plaintext(message << (T::BITS - 4));
+            let mut ciphertext = LweCiphertext::allocate(T::ZERO, lwe_dim.to_lwe_size());
+            public_key.encrypt_lwe_with_public_key(
+                &mut ciphertext,
+                &plaintext,
+                zero_encryption_count,
+                &mut secret_generator,
+            );
+
+            let mut decrypted = Plaintext(T::ZERO);
+            secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+            let rounded = decrypted.0.wrapping_add(T::ONE << (T::BITS - 5)) >> (T::BITS - 4);
+            assert_eq!(rounded, message);
+        }
+    }
+
+    #[test]
+    fn test_public_key_encryption_decrypts_u32() {
+        test_public_key_encryption_decrypts::<u32>()
+    }
+
+    #[test]
+    fn test_public_key_encryption_decrypts_u64() {
+        test_public_key_encryption_decrypts::<u64>()
+    }
+
+    #[test]
+    fn test_concat_then_sublist_recovers_pieces() {
+        use crate::core_crypto::commons::math::tensor::AsMutTensor;
+        use crate::core_crypto::prelude::LweSize;
+
+        let lwe_size = LweSize(5);
+        let pieces: Vec<_> = [2, 0, 3, 1]
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let mut piece = LweList::allocate(0_u64, lwe_size, CiphertextCount(count));
+                for (offset, value) in piece.as_mut_tensor().iter_mut().enumerate() {
+                    *value = (100 * index + offset) as u64;
+                }
+                piece
+            })
+            .collect();
+        let list = LweList::concat(&pieces.iter().collect::<Vec<_>>());
+        assert_eq!(list.count(), CiphertextCount(6));
+
+        let mut start = 0;
+        for piece in pieces.iter() {
+            let end = start + piece.count().0;
+            assert_eq!(list.sublist(start..end), piece.as_view());
+            start = end;
+        }
+        assert_eq!(list.sublist(0..6), list.as_view());
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried to take the ciphertexts 4..7")]
+    fn test_sublist_out_of_range_panics() {
+        use crate::core_crypto::prelude::LweSize;
+        let list = LweList::allocate(0_u64, LweSize(5), CiphertextCount(6));
+        let _ = list.sublist(4..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Tried to concatenate lists with different lwe sizes")]
+    fn test_concat_different_lwe_sizes_panics() {
+        use crate::core_crypto::prelude::LweSize;
+        let first = LweList::allocate(0_u64, LweSize(5), CiphertextCount(2));
+        let second = LweList::allocate(0_u64, LweSize(6), CiphertextCount(2));
+        let _ = LweList::concat(&[&first, &second]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The LWE dimension of a compact public key must be a power of two")]
+    fn test_compact_public_key_non_power_of_two_generation_panics() {
+        use crate::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+        let secret_key =
+            LweSecretKey::generate_binary(LweDimension(24), &mut new_secret_random_generator());
+        let mut public_key = LweCompactPublicKey::allocate(0_u64, LweDimension(24));
+        public_key.fill_with_compact_public_key(
+            &secret_key,
+            StandardDev(0.),
+            &mut new_encryption_random_generator(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The LWE dimension of a compact public key must be a power of two")]
+    fn test_compact_public_key_non_power_of_two_encryption_panics() {
+        use crate::core_crypto::commons::crypto::lwe::LweCompactPublicKey;
+        let public_key = LweCompactPublicKey::allocate(0_u64, LweDimension(24));
+        let mut ciphertext = LweCiphertext::allocate(0_u64, LweDimension(24).to_lwe_size());
+        public_key.encrypt_lwe(
+            &mut ciphertext,
+            &Plaintext(0_u64),
+            StandardDev(0.),
+            &mut new_secret_random_generator(),
+            &mut new_encryption_random_generator(),
+        );
+    }
 }
@@ -7,4 +7,6 @@ pub mod encoding;
 pub mod ggsw;
 pub mod glwe;
 pub mod lwe;
+pub mod noise_formulas;
 pub mod secret;
+pub mod security;
@@ -0,0 +1,318 @@
+//! Closed-form estimates of the noise behavior of ciphertexts.
+
+use crate::core_crypto::prelude::{
+    DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+    PolynomialSize, Variance,
+};
+
+/// Returns the probability that decrypting a ciphertext with the given noise variance rounds to
+/// the wrong message.
+///
+/// The variance is expressed over the normalized torus, i.e. the same representation as the one
+/// returned by [`DispersionParameter::get_variance`]. The message is assumed to be encoded with
+/// one bit of padding, so that the scaling factor is $\Delta = 1 / (2 \cdot m \cdot c)$ with $m$
+/// the message modulus and $c$ the carry modulus. A coefficient is decrypted properly as long as
+/// the absolute value of its noise stays below $\Delta / 2$.
+///
+/// The estimate relies on the following assumptions:
+///
+/// + The noise of each coefficient follows a centered gaussian distribution of the given variance,
+///   so that a single coefficient fails with probability $p =
+///   \mathrm{erfc}\left(\frac{\Delta}{2\sqrt{2}\sigma}\right)$.
+/// + The noises of the `poly_size` coefficients are independent, so that the probability that at
+///   least one of them fails is $1 - (1 - p)^N$. This is the worst case for a GLWE ciphertext whose
+///   coefficients are all decrypted. Use `PolynomialSize(1)` for a single LWE ciphertext.
+///
+/// The complementary error function is evaluated with a Chebyshev approximation whose relative
+/// error is below $1.2 \cdot 10^{-7}$ over the whole domain, so the result remains meaningful for
+/// very small failure probabilities.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::crypto::noise_formulas::decryption_failure_probability;
+/// use tfhe::core_crypto::prelude::{PolynomialSize, StandardDev};
+/// // With 2 bits of message and 2 bits of carry, the noise bound is 1/64. A standard deviation
+/// // of 1/192 puts it at 3 sigma.
+/// let p = decryption_failure_probability(StandardDev(1. / 192.), 4, 4, PolynomialSize(1));
+/// assert!((p - 2.6998e-3).abs() < 1e-6);
+/// ```
+pub fn decryption_failure_probability<D: DispersionParameter>(
+    variance: D,
+    message_modulus: usize,
+    carry_modulus: usize,
+    poly_size: PolynomialSize,
+) -> f64 {
+    assert!(
+        message_modulus != 0 && carry_modulus != 0,
+        "The message and carry moduli must be non-zero"
+    );
+    let std_dev = variance.get_standard_dev();
+    let bound = 1. / (4. * message_modulus as f64 * carry_modulus as f64);
+    if std_dev == 0. {
+        return 0.;
+    }
+    let single = erfc(bound / (std::f64::consts::SQRT_2 * std_dev)).min(1.);
+    // Computes 1 - (1 - p)^N without losing precision when p is small.
+    -f64::exp_m1(poly_size.0 as f64 * f64::ln_1p(-single))
+}
+
+/// Returns the variance of the noise added by an external product with a GGSW ciphertext using
+/// the given gadget decomposition.
+///
+/// The variances are expressed over the normalized torus, for ciphertexts over a modulus of
+/// $q = 2^{\mathrm{modulus\_bits}}$, a GLWE dimension $k$ and a polynomial size $N$. With
+/// $B = 2^{\mathrm{base\_log}}$ and $\ell$ the level count, the estimate is the sum of two
+/// terms:
+///
+/// + The amplification of the noise of the GGSW ciphertext, of variance $\sigma^2$, by the
+///   decomposition of the $(k + 1) N$ coefficients of the input GLWE ciphertext. Each of the $\ell
+///   (k + 1) N$ digits is assumed to be uniform in $[-B/2, B/2]$, which gives $\ell (k + 1) N
+///   \frac{B^2 + 2}{12} \sigma^2$.
+/// + The rounding of the input coefficients to the closest multiple of $q / B^\ell$, whose error is
+///   uniform and gets multiplied by the binary secret key, which gives $\left(1 + \frac{k
+///   N}{2}\right) \frac{B^{-2\ell} - q^{-2}}{12}$.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::crypto::noise_formulas::external_product_variance;
+/// use tfhe::core_crypto::prelude::{
+///     DecompositionBaseLog, DecompositionLevelCount, DispersionParameter, GlweDimension,
+///     PolynomialSize, StandardDev,
+/// };
+/// let variance = external_product_variance(
+///     64,
+///     DecompositionBaseLog(10),
+///     DecompositionLevelCount(3),
+///     StandardDev(2_f64.powi(-40)),
+///     GlweDimension(1),
+///     PolynomialSize(256),
+/// );
+/// assert!(variance.get_variance() < 2_f64.powi(-52));
+/// ```
+pub fn external_product_variance<D: DispersionParameter>(
+    modulus_bits: usize,
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+    key_variance: D,
+    glwe_dimension: GlweDimension,
+    poly_size: PolynomialSize,
+) -> Variance {
+    assert!(
+        base_log.0 * level_count.0 <= modulus_bits,
+        "The decomposition keeps more bits ({}) than the modulus has ({modulus_bits})",
+        base_log.0 * level_count.0
+    );
+    let mask_size = (glwe_dimension.0 * poly_size.0) as f64;
+    let digit_count = (level_count.0 * glwe_dimension.to_glwe_size().0 * poly_size.0) as f64;
+    let base = 2_f64.powi(base_log.0 as i32);
+    let amplification = digit_count * (base * base + 2.) / 12. * key_variance.get_variance();
+    let rounding = (1. + mask_size / 2.)
+        * (2_f64.powi(-2 * (base_log.0 * level_count.0) as i32)
+            - 2_f64.powi(-2 * modulus_bits as i32))
+        / 12.;
+    Variance(amplification + rounding)
+}
+
+/// Searches for the cheapest gadget decomposition whose external product noise stays below
+/// `target_variance`.
+///
+/// The noise of each candidate is estimated with [`external_product_variance`]. Since the cost of
+/// an external product grows linearly with the level count, the smallest level count for which
+/// some base log reaches the target is kept, along with the base log minimizing the noise for
+/// this level count. Returns `None` if no decomposition reaches the target.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::crypto::noise_formulas::optimal_decomposition;
+/// use tfhe::core_crypto::prelude::{
+///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize, StandardDev,
+///     Variance,
+/// };
+/// let key_noise = StandardDev(2_f64.powi(-40));
+/// let (base_log, level_count) = optimal_decomposition(
+///     64,
+///     Variance(2_f64.powi(-50)),
+///     key_noise,
+///     GlweDimension(1),
+///     PolynomialSize(256),
+/// )
+/// .unwrap();
+/// assert_eq!(base_log, DecompositionBaseLog(10));
+/// assert_eq!(level_count, DecompositionLevelCount(3));
+/// // No decomposition can remove the noise of the key.
+/// assert!(optimal_decomposition(
+///     64,
+///     Variance(2_f64.powi(-80)),
+///     key_noise,
+///     GlweDimension(1),
+///     PolynomialSize(256),
+/// )
+/// .is_none());
+/// ```
+pub fn optimal_decomposition<T: DispersionParameter, D: DispersionParameter>(
+    modulus_bits: usize,
+    target_variance: T,
+    key_variance: D,
+    glwe_dimension: GlweDimension,
+    poly_size: PolynomialSize,
+) -> Option<(DecompositionBaseLog, DecompositionLevelCount)> {
+    let target = target_variance.get_variance();
+    (1..=modulus_bits).find_map(|level_count| {
+        let level_count = DecompositionLevelCount(level_count);
+        (1..=modulus_bits / level_count.0)
+            .map(|base_log| {
+                let base_log = DecompositionBaseLog(base_log);
+                let variance = external_product_variance(
+                    modulus_bits,
+                    base_log,
+                    level_count,
+                    key_variance,
+                    glwe_dimension,
+                    poly_size,
+                );
+                (variance.get_variance(), base_log)
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .filter(|(variance, _)| *variance <= target)
+            .map(|(_, base_log)| (base_log, level_count))
+    })
+}
+
+// Complementary error function, from the Chebyshev fit of Numerical Recipes (`erfcc`).
+pub(crate) fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let poly = -1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let ans = t * f64::exp(-z * z + poly);
+    if x >= 0. {
+        ans
+    } else {
+        2. - ans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::{StandardDev, Variance};
+
+    fn assert_relative_eq(value: f64, expected: f64) {
+        assert!(
+            ((value - expected) / expected).abs() < 1e-5,
+            "{value} is not close to {expected}"
+        );
+    }
+
+    // Builds a standard deviation such that the noise bound sits at `k` sigma for 2 bits of
+    // message and 2 bits of carry.
+    fn std_dev_at(k: f64) -> StandardDev {
+        StandardDev(1. / (64. * k))
+    }
+
+    #[test]
+    fn test_gaussian_tail_reference_values() {
+        // Two-sided gaussian tail probabilities P(|X| > k sigma).
+        let references = [
+            (1., 3.173_105_08e-1),
+            (2., 4.550_026_39e-2),
+            (3., 2.699_796_06e-3),
+            (6., 1.973_174_78e-9),
+            (10., 1.523_970_60e-23),
+        ];
+        for (k, expected) in references {
+            let p = decryption_failure_probability(std_dev_at(k), 4, 4, PolynomialSize(1));
+            assert_relative_eq(p, expected);
+        }
+    }
+
+    #[test]
+    fn test_variance_and_std_dev_agree() {
+        let std_dev = std_dev_at(4.);
+        let variance = Variance(std_dev.0 * std_dev.0);
+        assert_relative_eq(
+            decryption_failure_probability(variance, 4, 4, PolynomialSize(1)),
+            decryption_failure_probability(std_dev, 4, 4, PolynomialSize(1)),
+        );
+    }
+
+    #[test]
+    fn test_polynomial_size_scaling() {
+        // For small probabilities the failure of a polynomial is close to the union bound.
+        let single = decryption_failure_probability(std_dev_at(7.), 4, 4, PolynomialSize(1));
+        let poly = decryption_failure_probability(std_dev_at(7.), 4, 4, PolynomialSize(1024));
+        assert_relative_eq(poly, 1024. * single);
+        // For large probabilities it saturates towards 1.
+        let poly = decryption_failure_probability(std_dev_at(1.), 4, 4, PolynomialSize(1024));
+        assert!(poly <= 1. && poly > 1. - 1e-9);
+    }
+
+    #[test]
+    fn test_optimal_decomposition_noise_in_simulation() {
+        use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+        use crate::core_crypto::commons::math::random::RandomGenerator;
+        use concrete_csprng::generators::SoftwareRandomGenerator;
+        use concrete_csprng::seeders::Seed;
+
+        let glwe_dimension = GlweDimension(1);
+        let poly_size = PolynomialSize(256);
+        let key_noise = StandardDev(2_f64.powi(-40));
+        let target = Variance(2_f64.powi(-50));
+        let (base_log, level_count) =
+            optimal_decomposition(64, target, key_noise, glwe_dimension, poly_size).unwrap();
+        let decomposer = SignedDecomposer::<u64>::new(base_log, level_count);
+
+        // Simulates the noise of a single output coefficient of the external product: the
+        // rounding errors of the mask are multiplied by the secret key and added to the one of
+        // the body, and every digit multiplies an independent noise of the GGSW ciphertext.
+        let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+        let mask_size = glwe_dimension.0 * poly_size.0;
+        let sample_count = 2_000;
+        let mut sum_of_squares = 0.;
+        for _ in 0..sample_count {
+            let mut noise = 0.;
+            for index in 0..glwe_dimension.to_glwe_size().0 * poly_size.0 {
+                let value: u64 = generator.random_uniform();
+                let rounding = value.wrapping_sub(decomposer.closest_representable(value)) as i64;
+                let weight = match index.cmp(&mask_size) {
+                    std::cmp::Ordering::Less => generator.random_uniform_binary::<u64>() as f64,
+                    std::cmp::Ordering::Equal => 1.,
+                    std::cmp::Ordering::Greater => 0.,
+                };
+                noise += weight * rounding as f64 / 2_f64.powi(64);
+                for term in decomposer.decompose(value) {
+                    let (error, _): (f64, f64) = generator.random_gaussian(0., key_noise.0);
+                    noise += term.value() as i64 as f64 * error;
+                }
+            }
+            sum_of_squares += noise * noise;
+        }
+        let measured = sum_of_squares / sample_count as f64;
+        assert!(measured < target.0, "{measured} >= {}", target.0);
+        // The estimate is close to the measured variance.
+        let estimated = external_product_variance(
+            64,
+            base_log,
+            level_count,
+            key_noise,
+            glwe_dimension,
+            poly_size,
+        );
+        assert!((measured / estimated.0 - 1.).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_noiseless_never_fails() {
+        let p = decryption_failure_probability(Variance(0.), 4, 4, PolynomialSize(2048));
+        assert_eq!(p, 0.);
+    }
+}
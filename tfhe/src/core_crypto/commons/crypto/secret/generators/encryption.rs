@@ -1,7 +1,8 @@
 #[cfg(feature = "__commons_parallel")]
 use crate::core_crypto::commons::math::random::ParallelByteRandomGenerator;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, Gaussian, RandomGenerable, RandomGenerator, Seed, Seeder, Uniform,
+    ByteRandomGenerator, Gaussian, RandomGenerable, RandomGenerator, Seed, Seeder, TUniform,
+    Uniform,
 };
 use crate::core_crypto::commons::math::tensor::AsMutTensor;
 
@@ -44,9 +45,14 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.mask.remaining_bytes()
     }
 
-    // Forks the generator, when splitting a bootstrap key into ggsw ct.
-    #[allow(dead_code)]
-    pub(crate) fn fork_bsk_to_ggsw<T: UnsignedInteger>(
+    /// Forks the generator into one child per GGSW ciphertext of a bootstrap key.
+    ///
+    /// Each child owns a disjoint, fixed-size portion of the byte streams of the mask and noise
+    /// generators, large enough to encrypt a GGSW ciphertext with the given parameters. The
+    /// children only depend on the state of the parent, which means that encrypting each GGSW
+    /// with its own child yields the same bootstrap key, whether the children are consumed
+    /// sequentially or in parallel.
+    pub fn fork_bsk_to_ggsw<T: UnsignedInteger>(
         &mut self,
         lwe_dimension: LweDimension,
         level: DecompositionLevelCount,
@@ -194,6 +200,28 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.noise
             .fill_tensor_with_random_gaussian(output, 0., std.get_standard_dev());
     }
+
+    // Sample a TUniform noise value of bound 2^bound_log2, using the noise generator.
+    pub(crate) fn random_tuniform_noise<Scalar>(&mut self, bound_log2: usize) -> Scalar
+    where
+        Scalar: RandomGenerable<TUniform>,
+    {
+        self.noise.random_tuniform(bound_log2)
+    }
+
+    // Fills the input tensor with TUniform noise of bound 2^bound_log2, using the noise
+    // generator.
+    pub(crate) fn fill_tensor_with_random_tuniform_noise<Scalar, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        bound_log2: usize,
+    ) where
+        Scalar: RandomGenerable<TUniform>,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        self.noise
+            .fill_tensor_with_random_tuniform(output, bound_log2);
+    }
 }
 
 #[cfg(feature = "__commons_parallel")]
@@ -416,20 +444,75 @@ fn noise_bytes_per_pfpksk(
     lwe_size.0 * noise_bytes_per_pfpksk_chunk(level, poly_size)
 }
 
-#[cfg(all(test, feature = "__commons_parallel"))]
+#[cfg(test)]
 mod test {
-    use crate::core_crypto::commons::crypto::bootstrap::StandardBootstrapKey;
-    use crate::core_crypto::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
-    use crate::core_crypto::commons::test_tools::{
-        new_encryption_random_generator, new_secret_random_generator,
-    };
+    use super::EncryptionRandomGenerator;
+    use crate::core_crypto::commons::crypto::secret::generators::DeterministicSeeder;
+    use crate::core_crypto::commons::math::random::Seed;
+    use crate::core_crypto::commons::math::tensor::{AsRefSlice, Tensor};
+    use crate::core_crypto::commons::test_tools::random_seed;
     use crate::core_crypto::prelude::{
-        DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
-        Variance,
+        DecompositionLevelCount, GlweSize, LogStandardDev, LweDimension, PolynomialSize,
     };
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+    use std::collections::HashSet;
+
+    // The mask and noise samples drawn by a child generator.
+    type ChildSamples = (Tensor<Vec<u64>>, Tensor<Vec<u64>>);
+
+    // Forks a generator built from `seed`, and returns the samples drawn by each child.
+    fn forked_samples(seed: Seed) -> Vec<ChildSamples> {
+        let poly_size = PolynomialSize(16);
+        let mut generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+            seed,
+            &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(seed),
+        );
+        generator
+            .fork_bsk_to_ggsw::<u64>(
+                LweDimension(8),
+                DecompositionLevelCount(2),
+                GlweSize(2),
+                poly_size,
+            )
+            .unwrap()
+            .map(|mut child| {
+                let mut mask = Tensor::allocate(0u64, poly_size.0);
+                child.fill_tensor_with_random_mask(&mut mask);
+                let mut noise = Tensor::allocate(0u64, poly_size.0);
+                child.fill_tensor_with_random_noise(&mut noise, LogStandardDev(-20.));
+                (mask, noise)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fork_bsk_to_ggsw_children_are_disjoint() {
+        let samples = forked_samples(random_seed());
+        assert_eq!(samples.len(), 8);
+        let mut masks = HashSet::new();
+        let mut noises = HashSet::new();
+        for (mask, noise) in samples.iter() {
+            assert!(mask.as_slice().iter().all(|a| masks.insert(*a)));
+            assert!(noise.as_slice().iter().all(|a| noises.insert(*a)));
+        }
+    }
+
+    #[test]
+    fn test_fork_bsk_to_ggsw_is_deterministic() {
+        let seed = random_seed();
+        assert_eq!(forked_samples(seed), forked_samples(seed));
+    }
 
+    #[cfg(feature = "__commons_parallel")]
     #[test]
     fn test_gaussian_sampling_margin_factor_does_not_panic() {
+        use crate::core_crypto::commons::crypto::bootstrap::StandardBootstrapKey;
+        use crate::core_crypto::commons::crypto::secret::{GlweSecretKey, LweSecretKey};
+        use crate::core_crypto::commons::test_tools::{
+            new_encryption_random_generator, new_secret_random_generator,
+        };
+        use crate::core_crypto::prelude::{DecompositionBaseLog, Variance};
+
         struct Params {
             glwe_size: GlweSize,
             poly_size: PolynomialSize,
@@ -1,7 +1,7 @@
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Gaussian, RandomGenerable, RandomGenerator, Seed,
 };
-use crate::core_crypto::commons::math::tensor::Tensor;
+use crate::core_crypto::commons::math::tensor::{AsMutTensor, Tensor};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::prelude::DispersionParameter;
 
@@ -35,6 +35,52 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
         self.0.random_uniform_ternary_tensor(length)
     }
 
+    /// Fills a tensor with random uniform binary values, i.e. values sampled uniformly in
+    /// `{0, 1}`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut tensor = Tensor::allocate(9 as u32, 1000);
+    /// generator.fill_tensor_with_random_binary(&mut tensor);
+    /// assert!(tensor.iter().all(|a| *a == 0 || *a == 1));
+    /// ```
+    pub fn fill_tensor_with_random_binary<Scalar, Tensorable>(&mut self, output: &mut Tensorable)
+    where
+        Scalar: UnsignedTorus,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        self.0.fill_tensor_with_random_uniform_binary(output)
+    }
+
+    /// Fills a tensor with random uniform ternary values, i.e. values sampled uniformly in
+    /// `{-1, 0, 1}`, where `-1` is represented by its wrapping opposite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut tensor = Tensor::allocate(9 as u32, 1000);
+    /// generator.fill_tensor_with_random_ternary(&mut tensor);
+    /// assert!(tensor.iter().all(|a| *a == 0 || *a == 1 || *a == u32::MAX));
+    /// ```
+    pub fn fill_tensor_with_random_ternary<Scalar, Tensorable>(&mut self, output: &mut Tensorable)
+    where
+        Scalar: UnsignedTorus,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        self.0.fill_tensor_with_random_uniform_ternary(output)
+    }
+
     // Returns a tensor with random uniform values.
     pub(crate) fn random_uniform_tensor<Scalar>(&mut self, length: usize) -> Tensor<Vec<Scalar>>
     where
@@ -53,3 +99,71 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
             .random_gaussian_tensor(length, 0.0, Scalar::GAUSSIAN_KEY_LOG_STD.get_standard_dev())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::SecretRandomGenerator;
+    use crate::core_crypto::commons::math::tensor::Tensor;
+    use crate::core_crypto::commons::math::torus::UnsignedTorus;
+    use crate::core_crypto::commons::test_tools::{new_secret_random_generator, random_seed};
+    use concrete_csprng::generators::SoftwareRandomGenerator;
+
+    fn test_fill_tensor_with_random_binary<T: UnsignedTorus>() {
+        let seed = random_seed();
+        let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(seed);
+        let mut tensor = Tensor::allocate(T::ONE << 4, 20_000);
+        generator.fill_tensor_with_random_binary(&mut tensor);
+        assert!(tensor.iter().all(|a| *a == T::ZERO || *a == T::ONE));
+        // Each value is expected 10 000 times, with a standard deviation of about 71.
+        let ones = tensor.iter().filter(|a| **a == T::ONE).count();
+        assert!((9_500..=10_500).contains(&ones), "{ones}");
+
+        // The output is reproducible from the seed of the generator.
+        let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(seed);
+        let mut other = Tensor::allocate(T::ZERO, 20_000);
+        generator.fill_tensor_with_random_binary(&mut other);
+        assert_eq!(tensor, other);
+    }
+
+    #[test]
+    fn test_fill_tensor_with_random_binary_u32() {
+        test_fill_tensor_with_random_binary::<u32>();
+    }
+
+    #[test]
+    fn test_fill_tensor_with_random_binary_u64() {
+        test_fill_tensor_with_random_binary::<u64>();
+    }
+
+    fn test_fill_tensor_with_random_ternary<T: UnsignedTorus>() {
+        let mut generator = new_secret_random_generator();
+        let mut tensor = Tensor::allocate(T::ONE << 4, 30_000);
+        generator.fill_tensor_with_random_ternary(&mut tensor);
+        let mut counts = [0_usize; 3];
+        for value in tensor.iter() {
+            if *value == T::ZERO {
+                counts[0] += 1;
+            } else if *value == T::ONE {
+                counts[1] += 1;
+            } else if *value == T::MAX {
+                counts[2] += 1;
+            } else {
+                panic!("{value:?} is not a ternary value");
+            }
+        }
+        // Each value is expected 10 000 times, with a standard deviation of about 82.
+        for count in counts {
+            assert!((9_500..=10_500).contains(&count), "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_fill_tensor_with_random_ternary_u32() {
+        test_fill_tensor_with_random_ternary::<u32>();
+    }
+
+    #[test]
+    fn test_fill_tensor_with_random_ternary_u64() {
+        test_fill_tensor_with_random_ternary::<u64>();
+    }
+}
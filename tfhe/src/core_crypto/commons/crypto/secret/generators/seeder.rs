@@ -30,6 +30,19 @@ use crate::core_crypto::commons::math::random::{
 ///
 /// Pseudo Random indicates that for the same initial state (here Seed) it will generate the same
 /// exact set of numbers in the same order, making it deterministic.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_csprng::generators::SoftwareRandomGenerator;
+/// use concrete_csprng::seeders::{Seed, Seeder};
+/// use tfhe::core_crypto::commons::crypto::secret::generators::DeterministicSeeder;
+/// let mut first = DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(42));
+/// let mut second = DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(42));
+/// let seed = first.seed();
+/// assert_eq!(seed, second.seed());
+/// assert_ne!(seed, first.seed());
+/// ```
 pub struct DeterministicSeeder<G: ByteRandomGenerator> {
     generator: RandomGenerator<G>,
 }
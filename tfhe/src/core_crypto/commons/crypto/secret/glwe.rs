@@ -13,7 +13,7 @@ use crate::core_crypto::commons::math::polynomial::PolynomialList;
 #[cfg(feature = "__commons_parallel")]
 use crate::core_crypto::commons::math::random::ParallelByteRandomGenerator;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, Gaussian, RandomGenerable, Seeder,
+    ByteRandomGenerator, Gaussian, RandomGenerable, Seeder, TUniform,
 };
 use crate::core_crypto::commons::math::tensor::{
     ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
@@ -383,6 +383,29 @@ where
         GlweDimension(self.as_tensor().len() / self.poly_size.0)
     }
 
+    /// Returns the coefficients of the secret key.
+    ///
+    /// # Security
+    ///
+    /// The returned coefficients are the secret itself. Anyone who obtains them can decrypt every
+    /// ciphertext encrypted under this key, so they must never be logged, serialized to an
+    /// untrusted location, or sent over an unencrypted channel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::secret::*;
+    /// use tfhe::core_crypto::prelude::PolynomialSize;
+    /// let secret_key = GlweSecretKey::binary_from_container(vec![0u32, 1, 1, 0], PolynomialSize(2));
+    /// assert_eq!(secret_key.as_coefficients(), &[0, 1, 1, 0]);
+    /// ```
+    pub fn as_coefficients(&self) -> &[<Self as AsRefTensor>::Element]
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor().as_slice()
+    }
+
     /// Returns the size of the secret key polynomials.
     ///
     /// # Example
@@ -566,6 +589,72 @@ where
         );
     }
 
+    /// Encrypts a single GLWE ciphertext, with noise coefficients sampled from the TUniform
+    /// distribution of bound $2^b$, where $b$ is `bound_log2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::*;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, GlweSize, PolynomialSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let plaintexts = PlaintextList::from_container(vec![1u32 << 28; 256]);
+    /// let mut ciphertext = GlweCiphertext::allocate(0u32, PolynomialSize(256), GlweSize(3));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// secret_key.encrypt_glwe_with_tuniform_noise(
+    ///     &mut ciphertext,
+    ///     &plaintexts,
+    ///     10,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut decrypted = PlaintextList::from_container(vec![0u32; 256]);
+    /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()) {
+    ///     let noise = dec.0.wrapping_sub(plain.0) as i32;
+    ///     assert!(noise.abs() <= 1 << 10);
+    /// }
+    /// ```
+    pub fn encrypt_glwe_with_tuniform_noise<Cont1, Cont2, Scalar, Gen>(
+        &self,
+        encrypted: &mut GlweCiphertext<Cont1>,
+        encoded: &PlaintextList<Cont2>,
+        bound_log2: usize,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<Cont1>: AsMutTensor<Element = Scalar>,
+        PlaintextList<Cont2>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + RandomGenerable<TUniform>,
+        Gen: ByteRandomGenerator,
+    {
+        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        ck_dim_eq!(encrypted.mask_size().0 => self.key_size().0);
+
+        let (mut body, mut masks) = encrypted.get_mut_body_and_mask();
+
+        generator.fill_tensor_with_random_tuniform_noise(&mut body, bound_log2);
+        generator.fill_tensor_with_random_mask(&mut masks);
+        body.as_mut_polynomial().update_with_wrapping_add_multisum(
+            &masks.as_polynomial_list(),
+            &self.as_polynomial_list(),
+        );
+        body.as_mut_polynomial()
+            .update_with_wrapping_add(&encoded.as_polynomial());
+    }
+
     pub fn encrypt_seeded_glwe_with_existing_generator<Scalar, Cont1, Cont2, NoiseParameter, Gen>(
         &self,
         encrypted: &mut GlweSeededCiphertext<Cont1>,
@@ -970,6 +1059,94 @@ where
         }
     }
 
+    /// Returns an iterator which lazily encrypts each plaintext list yielded by `plaintexts` into
+    /// a freshly allocated GLWE ciphertext.
+    ///
+    /// The ciphertexts are produced in the same order, and with the same randomness, as if the
+    /// plaintexts had been encrypted with successive calls to
+    /// [`GlweSecretKey::encrypt_glwe`] using the same generator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::glwe::GlweList;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::*;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use tfhe::core_crypto::prelude::{
+    ///     CiphertextCount, GlweDimension, LogStandardDev, PolynomialSize,
+    /// };
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(256),
+    ///     PolynomialSize(2),
+    ///     &mut secret_generator,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-60.);
+    /// let plaintexts = PlaintextList::from_container(vec![1000 as u32, 2000, 3000, 4000]);
+    ///
+    /// let mut batch = GlweList::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(2),
+    ///     GlweDimension(256),
+    ///     CiphertextCount(2),
+    /// );
+    /// let mut batch_generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+    ///     Seed(0),
+    ///     &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(1)),
+    /// );
+    /// secret_key.encrypt_glwe_list(&mut batch, &plaintexts, noise, &mut batch_generator);
+    ///
+    /// let mut stream_generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+    ///     Seed(0),
+    ///     &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(1)),
+    /// );
+    /// let inputs = vec![
+    ///     PlaintextList::from_container(vec![1000 as u32, 2000]),
+    ///     PlaintextList::from_container(vec![3000 as u32, 4000]),
+    /// ];
+    /// let streamed: Vec<_> = secret_key
+    ///     .encrypt_stream(inputs.into_iter(), noise, &mut stream_generator)
+    ///     .collect();
+    ///
+    /// assert_eq!(streamed.len(), 2);
+    /// for (streamed, batched) in streamed.iter().zip(batch.ciphertext_iter()) {
+    ///     assert_eq!(
+    ///         streamed.as_tensor().as_slice(),
+    ///         batched.as_tensor().as_slice()
+    ///     );
+    /// }
+    /// ```
+    pub fn encrypt_stream<'a, Scalar, Plaintexts, NoiseParameter, Gen>(
+        &'a self,
+        plaintexts: Plaintexts,
+        noise_parameters: NoiseParameter,
+        generator: &'a mut EncryptionRandomGenerator<Gen>,
+    ) -> impl Iterator<Item = GlweCiphertext<Vec<Scalar>>> + 'a
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Plaintexts: Iterator<Item = PlaintextList<Vec<Scalar>>> + 'a,
+        NoiseParameter: DispersionParameter + 'a,
+        Scalar: UnsignedTorus,
+        Gen: ByteRandomGenerator,
+    {
+        let glwe_size = self.key_size().to_glwe_size();
+        plaintexts.map(move |encoded| {
+            let mut ciphertext = GlweCiphertext::allocate(
+                Scalar::ZERO,
+                PolynomialSize(encoded.count().0),
+                glwe_size,
+            );
+            self.encrypt_glwe(&mut ciphertext, &encoded, noise_parameters, generator);
+            ciphertext
+        })
+    }
+
     /// Decrypts a single GLWE ciphertext.
     ///
     /// See ['GlweSecretKey::encrypt_glwe`] for an example.
@@ -8,7 +8,7 @@ use crate::core_crypto::commons::crypto::secret::generators::{
 #[cfg(feature = "__commons_parallel")]
 use crate::core_crypto::commons::math::random::ParallelByteRandomGenerator;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, Gaussian, RandomGenerable, Seeder,
+    ByteRandomGenerator, Gaussian, RandomGenerable, Seeder, TUniform,
 };
 use crate::core_crypto::commons::math::tensor::{
     AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
@@ -282,6 +282,42 @@ impl<Cont> LweSecretKey<UniformKeyKind, Cont> {
     }
 }
 
+impl<Kind, Scalar> LweSecretKey<Kind, Vec<Scalar>>
+where
+    Kind: KeyKind,
+{
+    /// Creates a secret key from coefficients generated elsewhere, e.g. exported with
+    /// [`LweSecretKey::as_coefficients`]. The dimension of the key is the number of coefficients.
+    ///
+    /// # Notes
+    ///
+    /// The coefficients are not checked against the key kind: it is up to the caller to make sure
+    /// that they were sampled from the appropriate distribution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::SecretRandomGenerator;
+    /// use tfhe::core_crypto::commons::crypto::secret::*;
+    /// use tfhe::core_crypto::prelude::{BinaryKeyKind, LweDimension};
+    /// let mut generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key: LweSecretKey<_, Vec<u32>> =
+    ///     LweSecretKey::generate_binary(LweDimension(256), &mut generator);
+    /// let coefficients = secret_key.as_coefficients().to_vec();
+    /// let imported = LweSecretKey::<BinaryKeyKind, _>::from_coefficients(coefficients);
+    /// assert_eq!(imported.key_size(), LweDimension(256));
+    /// assert_eq!(imported.as_coefficients(), secret_key.as_coefficients());
+    /// ```
+    pub fn from_coefficients(coefficients: Vec<Scalar>) -> Self {
+        LweSecretKey {
+            tensor: Tensor::from_container(coefficients),
+            kind: PhantomData,
+        }
+    }
+}
+
 impl<Kind, Cont> LweSecretKey<Kind, Cont>
 where
     Kind: KeyKind,
@@ -304,6 +340,28 @@ where
         LweDimension(self.as_tensor().len())
     }
 
+    /// Returns the coefficients of the secret key.
+    ///
+    /// # Security
+    ///
+    /// The returned coefficients are the secret itself. Anyone who obtains them can decrypt every
+    /// ciphertext encrypted under this key, so they must never be logged, serialized to an
+    /// untrusted location, or sent over an unencrypted channel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::secret::*;
+    /// let secret_key = LweSecretKey::binary_from_container(vec![0u32, 1, 1, 0]);
+    /// assert_eq!(secret_key.as_coefficients(), &[0, 1, 1, 0]);
+    /// ```
+    pub fn as_coefficients(&self) -> &[<Self as AsRefTensor>::Element]
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor().as_slice()
+    }
+
     fn fill_lwe_mask_and_body_for_encryption<OutputCont, Scalar, Gen>(
         &self,
         output_body: &mut LweBody<Scalar>,
@@ -356,6 +414,59 @@ where
         );
     }
 
+    /// Encrypts a single ciphertext, with a noise sampled from the TUniform distribution of bound
+    /// $2^b$, where $b$ is `bound_log2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::Plaintext;
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweCiphertext;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::*;
+    /// use tfhe::core_crypto::prelude::{LweDimension, LweSize};
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let plaintext = Plaintext(1u32 << 28);
+    /// let mut ciphertext = LweCiphertext::allocate(0u32, LweSize(257));
+    /// secret_key.encrypt_lwe_with_tuniform_noise(
+    ///     &mut ciphertext,
+    ///     &plaintext,
+    ///     10,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut decrypted = Plaintext(0u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let noise = decrypted.0.wrapping_sub(plaintext.0) as i32;
+    /// assert!(noise.abs() <= 1 << 10);
+    /// ```
+    pub fn encrypt_lwe_with_tuniform_noise<OutputCont, Scalar, Gen>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        bound_log2: usize,
+        generator: &mut EncryptionRandomGenerator<Gen>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + RandomGenerable<TUniform>,
+        Gen: ByteRandomGenerator,
+    {
+        let (output_body, mut output_mask) = output.get_mut_body_and_mask();
+
+        generator.fill_tensor_with_random_mask(&mut output_mask);
+        output_body.0 = generator
+            .random_tuniform_noise::<Scalar>(bound_log2)
+            .wrapping_add(output_mask.compute_multisum(self))
+            .wrapping_add(encoded.0);
+    }
+
     /// Encrypts a single seeded ciphertext.
     pub fn encrypt_seeded_lwe<Scalar, NoiseParameter, NoiseSeeder, Gen>(
         &self,
@@ -587,7 +698,55 @@ where
 
     /// Decrypts a list of ciphertexts.
     ///
-    /// See ['encrypt_lwe_list'] for an example.
+    /// The plaintexts are written in `output` in the order of the ciphertexts of `cipher`, which
+    /// makes this method the inverse of [`encrypt_lwe_list`](`Self::encrypt_lwe_list`), up to the
+    /// noise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` and `cipher` do not have the same number of elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::{Seed, UnixSeeder};
+    /// use tfhe::core_crypto::commons::crypto::encoding::PlaintextList;
+    /// use tfhe::core_crypto::commons::crypto::lwe::LweList;
+    /// use tfhe::core_crypto::commons::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use tfhe::core_crypto::commons::crypto::secret::LweSecretKey;
+    /// use tfhe::core_crypto::commons::math::tensor::AsRefTensor;
+    /// use tfhe::core_crypto::prelude::{
+    ///     CiphertextCount, LogStandardDev, LweDimension, PlaintextCount,
+    /// };
+    /// let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut encryption_generator =
+    ///     EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(Seed(0), &mut UnixSeeder::new(0));
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    ///
+    /// let messages = PlaintextList::from_container(vec![1u32 << 28, 2 << 28, 3 << 28, 15 << 28]);
+    /// let mut ciphertexts =
+    ///     LweList::allocate(0u32, LweDimension(256).to_lwe_size(), CiphertextCount(4));
+    /// secret_key.encrypt_lwe_list(
+    ///     &mut ciphertexts,
+    ///     &messages,
+    ///     LogStandardDev::from_log_standard_dev(-25.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+    /// secret_key.decrypt_lwe_list(&mut decrypted, &ciphertexts);
+    /// for (decrypted, message) in decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .zip(messages.as_tensor().iter())
+    /// {
+    ///     let error = decrypted.wrapping_sub(*message);
+    ///     assert!(error.min(error.wrapping_neg()) < 1 << 16);
+    /// }
+    /// ```
     pub fn decrypt_lwe_list<Scalar, EncodedCont, CipherCont>(
         &self,
         output: &mut PlaintextList<EncodedCont>,
@@ -598,9 +757,9 @@ where
         LweList<CipherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        debug_assert!(
+        assert!(
             output.count().0 == cipher.count().0,
-            "Tried to decrypt a list into one with incompatible size.Expected {} found {}",
+            "Tried to decrypt a list into one with incompatible size. Expected {} found {}",
             output.count().0,
             cipher.count().0
         );
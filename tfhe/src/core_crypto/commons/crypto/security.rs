@@ -0,0 +1,146 @@
+//! Estimates of the security of LWE and GLWE parameters.
+
+use crate::core_crypto::prelude::{
+    DispersionParameter, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// The base-2 logarithm of the ciphertext modulus assumed by the estimates.
+const MODULUS_LOG: f64 = 64.;
+
+/// The standard deviation of the coefficients of a uniform binary secret key.
+const BINARY_SECRET_STD_DEV: f64 = 0.5;
+
+/// The smallest and largest block sizes considered by the estimates.
+const MIN_BLOCK_SIZE: usize = 40;
+const MAX_BLOCK_SIZE: usize = 4096;
+
+/// An estimated security level, in bits.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SecurityLevel(pub f64);
+
+impl SecurityLevel {
+    /// Estimates the security of LWE ciphertexts of dimension `lwe_dimension` with binary secret
+    /// keys and a gaussian noise of dispersion `noise`, over a modulus of $2^{64}$.
+    ///
+    /// The estimate follows the primal uSVP attack, in the way the lattice estimator evaluates
+    /// it:
+    ///
+    /// + For $m$ LWE samples, the attack builds an embedding lattice of dimension $d = n + m + 1$,
+    ///   in which the secret coefficients are rescaled to the size of the noise.
+    /// + The attack succeeds with BKZ of block size $\beta$ if $\sigma \sqrt{\beta} \le
+    ///   \delta^{2\beta - d - 1} \mathrm{Vol}^{1/d}$, where $\sigma$ is the noise standard
+    ///   deviation, $\delta$ the root Hermite factor reached by BKZ-$\beta$ and $\mathrm{Vol}$ the
+    ///   volume of the lattice. The smallest such $\beta$ over all $m$ is kept.
+    /// + The cost of BKZ-$\beta$ is $2^{0.292 \beta + 16.4 + \log_2(8d)}$ operations, using the
+    ///   classical sieving core-SVP model.
+    ///
+    /// This gives figures close to the ones of the lattice estimator for the usual parameter
+    /// sets. They are only meant to compare parameter sets with each other: the estimates do not
+    /// cover the other attacks, and block sizes are clamped between 40 and 4096. GLWE ciphertexts
+    /// are estimated by [`estimate_glwe`](`SecurityLevel::estimate_glwe`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::security::SecurityLevel;
+    /// use tfhe::core_crypto::prelude::{LweDimension, StandardDev};
+    /// // The LWE parameters of `PARAM_MESSAGE_2_CARRY_2`.
+    /// let security = SecurityLevel::estimate(LweDimension(742), StandardDev(7.069849454709433e-6));
+    /// assert!(security >= SecurityLevel(128.));
+    /// // Reducing the amount of noise lowers the security.
+    /// let weaker = SecurityLevel::estimate(LweDimension(742), StandardDev(7.069849454709433e-8));
+    /// assert!(weaker < security);
+    /// ```
+    pub fn estimate<D: DispersionParameter>(
+        lwe_dimension: LweDimension,
+        noise: D,
+    ) -> SecurityLevel {
+        let n = lwe_dimension.0;
+        let noise_log = noise.get_standard_dev().log2() + MODULUS_LOG;
+        // Rescales the secret coefficients to the size of the noise.
+        let scaling_log = noise_log - BINARY_SECRET_STD_DEV.log2();
+        let (block_size, dimension) = (MIN_BLOCK_SIZE..=MAX_BLOCK_SIZE)
+            .find_map(|block_size| {
+                let beta = block_size as f64;
+                let delta_log = root_hermite_factor_log(beta);
+                (1..=2 * n.max(1))
+                    .map(|m| n + m + 1)
+                    .find(|&d| {
+                        let m = (d - n - 1) as f64;
+                        let volume_log = m * MODULUS_LOG + n as f64 * scaling_log;
+                        noise_log + 0.5 * beta.log2()
+                            <= (2. * beta - d as f64 - 1.) * delta_log + volume_log / d as f64
+                    })
+                    .map(|d| (block_size, d))
+            })
+            .unwrap_or((MAX_BLOCK_SIZE, n + 2 * n.max(1) + 1));
+        SecurityLevel(0.292 * block_size as f64 + 16.4 + (8. * dimension as f64).log2())
+    }
+
+    /// Estimates the security of GLWE ciphertexts of dimension `glwe_dimension` and polynomial size
+    /// `polynomial_size`, with binary secret keys and a gaussian noise of dispersion `noise`, over
+    /// a modulus of $2^{64}$.
+    ///
+    /// The GLWE ciphertext is estimated as an LWE ciphertext of dimension
+    /// `glwe_dimension * polynomial_size`, with [`estimate`](`SecurityLevel::estimate`). This does
+    /// not account for attacks exploiting the structure of the polynomial ring.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::crypto::security::SecurityLevel;
+    /// use tfhe::core_crypto::prelude::{GlweDimension, LweDimension, PolynomialSize, StandardDev};
+    /// // The GLWE parameters of `PARAM_MESSAGE_2_CARRY_2`.
+    /// let noise = StandardDev(2.9403601535432533e-16);
+    /// let security = SecurityLevel::estimate_glwe(GlweDimension(1), PolynomialSize(2048), noise);
+    /// assert!(security >= SecurityLevel(128.));
+    /// assert_eq!(security, SecurityLevel::estimate(LweDimension(2048), noise));
+    /// ```
+    pub fn estimate_glwe<D: DispersionParameter>(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        noise: D,
+    ) -> SecurityLevel {
+        SecurityLevel::estimate(LweDimension(glwe_dimension.0 * polynomial_size.0), noise)
+    }
+}
+
+// The base-2 logarithm of the root Hermite factor reached by BKZ with block size `beta`.
+fn root_hermite_factor_log(beta: f64) -> f64 {
+    use std::f64::consts::{E, PI};
+    ((PI * beta).powf(1. / beta) * beta / (2. * PI * E)).log2() / (2. * (beta - 1.))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::StandardDev;
+
+    #[test]
+    fn test_known_parameter_sets_are_around_128_bits() {
+        // The LWE and GLWE parameters of `PARAM_MESSAGE_2_CARRY_2`.
+        let lwe = SecurityLevel::estimate(LweDimension(742), StandardDev(7.069849454709433e-6));
+        let glwe = SecurityLevel::estimate_glwe(
+            GlweDimension(1),
+            PolynomialSize(2048),
+            StandardDev(2.9403601535432533e-16),
+        );
+        for security in [lwe, glwe] {
+            assert!(
+                security.0 > 125. && security.0 < 140.,
+                "Implausible security estimate: {}",
+                security.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_security_grows_with_dimension_and_noise() {
+        let noise = StandardDev(2_f64.powi(-20));
+        let small = SecurityLevel::estimate(LweDimension(512), noise);
+        let large = SecurityLevel::estimate(LweDimension(1024), noise);
+        assert!(small < large);
+        let noisier = SecurityLevel::estimate(LweDimension(512), StandardDev(2_f64.powi(-10)));
+        assert!(small < noisier);
+    }
+}
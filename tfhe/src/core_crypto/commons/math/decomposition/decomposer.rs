@@ -147,7 +147,11 @@ where
     ///
     /// # Warning
     ///
-    /// The returned iterator yields the terms $\tilde{\theta}\_i$ in order of decreasing $i$.
+    /// The returned iterator yields the terms $\tilde{\theta}\_i$ in order of decreasing $i$,
+    /// that is from the least significant term, of level `level_count`, to the most significant
+    /// one, of level 1. The terms are computed in this order, since rounding each of them to a
+    /// balanced digit carries into the next more significant one. Collect and reverse the terms
+    /// to process them from the most significant one.
     ///
     /// # Example
     ///
@@ -157,6 +161,11 @@ where
     /// use tfhe::core_crypto::prelude::{DecompositionBaseLog, DecompositionLevelCount};
     /// let decomposer =
     ///     SignedDecomposer::<u32>::new(DecompositionBaseLog(4), DecompositionLevelCount(3));
+    /// let levels: Vec<_> = decomposer
+    ///     .decompose(1_340_987_234_u32)
+    ///     .map(|term| term.level().0)
+    ///     .collect();
+    /// assert_eq!(levels, vec![3, 2, 1]);
     /// for term in decomposer.decompose(1_340_987_234_u32) {
     ///     assert!(1 <= term.level().0);
     ///     assert!(term.level().0 <= 3);
@@ -210,7 +219,9 @@ where
     /// # Warning
     ///
     /// The returned iterator yields the terms $(\tilde{\theta}^{(a)}\_i)\_{a\in\mathbb{N}}$ in
-    /// order of decreasing $i$.
+    /// order of decreasing $i$, that is from the least significant terms, of level `level_count`,
+    /// to the most significant ones, of level 1, as [`decompose`](`SignedDecomposer::decompose`)
+    /// does.
     ///
     /// # Example
     ///
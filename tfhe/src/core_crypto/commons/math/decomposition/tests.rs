@@ -58,6 +58,36 @@ fn test_decompose_recompose_u64() {
     test_decompose_recompose::<u64>()
 }
 
+fn test_decompose_terms_sum_to_closest_representable<
+    T: UnsignedInteger + Debug + RandomGenerable<Uniform>,
+>() {
+    // Checks that summing the terms by hand, without going through `recompose`, brings the
+    // closest representable, and that the terms are yielded from the least significant level.
+    for _ in 0..100_000 {
+        let decomposer = random_decomp::<T>();
+        let input = any_uint::<T>();
+        let mut expected_level = decomposer.level_count;
+        let mut sum = T::ZERO;
+        for term in decomposer.decompose(input) {
+            assert_eq!(term.level().0, expected_level);
+            expected_level -= 1;
+            sum = sum.wrapping_add(term.to_recomposition_summand());
+        }
+        assert_eq!(expected_level, 0);
+        assert_eq!(sum, decomposer.closest_representable(input));
+    }
+}
+
+#[test]
+fn test_decompose_terms_sum_to_closest_representable_u32() {
+    test_decompose_terms_sum_to_closest_representable::<u32>()
+}
+
+#[test]
+fn test_decompose_terms_sum_to_closest_representable_u64() {
+    test_decompose_terms_sum_to_closest_representable::<u64>()
+}
+
 fn test_decompose_recompose_tensor<T: UnsignedInteger + Debug + RandomGenerable<Uniform>>()
 where
     <T as UnsignedInteger>::Signed: Debug + SignedInteger,
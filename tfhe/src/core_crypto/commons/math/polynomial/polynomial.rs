@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use std::iter::Iterator;
 
 use crate::core_crypto::commons::math::tensor::{
-    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefTensor, Tensor,
+    ck_dim_eq, ck_dim_pow2, tensor_traits, AsMutSlice, AsMutTensor, AsRefTensor, Tensor,
 };
 
 use super::*;
@@ -157,6 +157,10 @@ impl<Cont> Polynomial<Cont> {
 
     /// Returns the monomial of a given degree.
     ///
+    /// # Panics
+    ///
+    /// Panics if the degree is not smaller than the polynomial size.
+    ///
     /// # Example
     ///
     /// ```
@@ -166,6 +170,9 @@ impl<Cont> Polynomial<Cont> {
     /// assert_eq!(*mono.get_coefficient(), 16_u32);
     /// let mono = poly.get_monomial(MonomialDegree(2));
     /// assert_eq!(*mono.get_coefficient(), 19_u32);
+    /// // The leading coefficient is the one of degree N - 1.
+    /// let leading = MonomialDegree(poly.polynomial_size().0 - 1);
+    /// assert_eq!(*poly.get_monomial(leading).get_coefficient(), 3_u32);
     /// ```
     pub fn get_monomial(
         &self,
@@ -174,6 +181,7 @@ impl<Cont> Polynomial<Cont> {
     where
         Self: AsRefTensor,
     {
+        self.check_degree(degree);
         Monomial::from_container(
             self.as_tensor()
                 .get_sub(degree.0..=degree.0)
@@ -238,6 +246,10 @@ impl<Cont> Polynomial<Cont> {
 
     /// Returns the mutable monomial of a given degree.
     ///
+    /// # Panics
+    ///
+    /// Panics if the degree is not smaller than the polynomial size.
+    ///
     /// # Example
     ///
     /// ```
@@ -247,6 +259,10 @@ impl<Cont> Polynomial<Cont> {
     /// mono.set_coefficient(18);
     /// let mono = poly.get_monomial(MonomialDegree(0));
     /// assert_eq!(*mono.get_coefficient(), 18);
+    /// *poly
+    ///     .get_mut_monomial(MonomialDegree(4))
+    ///     .get_mut_coefficient() = 7;
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(4)).get_coefficient(), 7);
     /// ```
     pub fn get_mut_monomial(
         &mut self,
@@ -255,6 +271,7 @@ impl<Cont> Polynomial<Cont> {
     where
         Self: AsMutTensor,
     {
+        self.check_degree(degree);
         Monomial::from_container(
             self.as_mut_tensor()
                 .get_sub_mut(degree.0..=degree.0)
@@ -263,8 +280,13 @@ impl<Cont> Polynomial<Cont> {
         )
     }
 
-    /// Fills the current polynomial, with the result of the (slow) product of
-    /// two polynomials, reduced modulo $(X^N + 1)$.
+    /// Fills the current polynomial, with the result of the (slow) schoolbook product of two
+    /// polynomials in the ring $\mathbb{Z}\_q\[X\]/(X^N + 1)$, i.e. their product reduced modulo
+    /// $(X^N + 1)$.
+    ///
+    /// The coefficients are computed with wrapping integer arithmetic, matching the torus
+    /// representation. This is a simple reference implementation, against which faster
+    /// multiplication algorithms can be checked.
     ///
     /// # Example
     ///
@@ -350,12 +372,7 @@ impl<Cont> Polynomial<Cont> {
         ck_dim_eq!(self.polynomial_size() => p.polynomial_size(), q.polynomial_size());
 
         // check dimensions are a power of 2
-        debug_assert!(
-            f64::abs(
-                f64::floor(f64::log2(p.polynomial_size().0 as f64))
-                    - f64::log2(p.polynomial_size().0 as f64)
-            ) < f64::EPSILON
-        );
+        ck_dim_pow2!(p.polynomial_size().0);
 
         let poly_size = self.polynomial_size().0;
 
@@ -417,6 +434,49 @@ impl<Cont> Polynomial<Cont> {
             .update_with_wrapping_sub(&a1.get_sub(bottom));
     }
 
+    /// Adds the result of the product between two polynomials, reduced modulo $(X^N + 1)$, to the
+    /// current polynomial.
+    ///
+    /// The product is computed with the Karatsuba algorithm when the polynomial size is a power of
+    /// two large enough for the induction, and with the schoolbook algorithm otherwise. In both
+    /// cases, the coefficients whose degree sums to $N$ or more wrap around with a sign flip, as
+    /// $X^N = -1$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// // (1 + 2X + 3X^2) * (X + X^2) = X + 3X^2 + 5X^3 + 3X^4 = -5 + -2X + 3X^2
+    /// let lhs = Polynomial::from_container(vec![1_u32, 2, 3]);
+    /// let rhs = Polynomial::from_container(vec![0_u32, 1, 1]);
+    /// let mut res = Polynomial::from_container(vec![10_u32, 10, 10]);
+    /// res.update_with_wrapping_add_karatsuba_mul(&lhs, &rhs);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 5);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 13);
+    /// ```
+    pub fn update_with_wrapping_add_karatsuba_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        let poly_size = self.polynomial_size();
+        let mut product = Polynomial::allocate(Coef::ZERO, poly_size);
+        // The Karatsuba induction stops on halves of `KARATUSBA_STOP` coefficients
+        if poly_size.0.is_power_of_two() && poly_size.0 >= 2 * KARATUSBA_STOP {
+            product.fill_with_karatsuba_mul(lhs, rhs);
+        } else {
+            product.fill_with_wrapping_mul(lhs, rhs);
+        }
+        self.update_with_wrapping_add(&product);
+    }
+
     /// Adds the sum of the element-wise product between two lists of integer polynomial to the
     /// current polynomial.
     ///
@@ -762,6 +822,31 @@ impl<Cont> Polynomial<Cont> {
             .for_each(|a| *a = a.wrapping_neg());
     }
 
+    /// Divides (mod $(X^N+1)$), the current polynomial with a monomial of a given degree, and a
+    /// coefficient of one.
+    ///
+    /// This is the inverse of
+    /// [`update_with_wrapping_monic_monomial_mul`](Self::update_with_wrapping_monic_monomial_mul),
+    /// and is equivalent to
+    /// [`update_with_wrapping_unit_monomial_div`](Self::update_with_wrapping_unit_monomial_div).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+    /// poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(5));
+    /// poly.update_with_wrapping_monic_monomial_div(MonomialDegree(5));
+    /// assert_eq!(poly, Polynomial::from_container(vec![1u8, 2, 3]));
+    /// ```
+    pub fn update_with_wrapping_monic_monomial_div<Coef>(&mut self, monomial_degree: MonomialDegree)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.update_with_wrapping_unit_monomial_div(monomial_degree)
+    }
+
     /// Adds multiple integer polynomials to the current one.
     ///
     /// # Examples
@@ -830,6 +915,18 @@ impl<Cont> Polynomial<Cont> {
             self.update_with_wrapping_sub(&poly);
         }
     }
+
+    fn check_degree(&self, degree: MonomialDegree)
+    where
+        Self: AsRefTensor,
+    {
+        assert!(
+            degree.0 < self.polynomial_size().0,
+            "Tried to access the monomial of degree {} of a polynomial of size {}.",
+            degree.0,
+            self.polynomial_size().0
+        );
+    }
 }
 
 /// function used to compute the induction for the karatsuba algorithm
@@ -94,6 +94,116 @@ fn test_multiply_karatsuba<T: UnsignedTorus>() {
     }
 }
 
+/// test that the accumulated product matches the schoolbook product, on polynomial sizes handled
+/// by both the karatsuba and the schoolbook paths
+fn test_update_with_wrapping_add_karatsuba_mul<T: UnsignedTorus>() {
+    let mut generator = new_random_generator();
+    for polynomial_size in [
+        PolynomialSize(16),
+        PolynomialSize(100),
+        PolynomialSize(1024),
+    ] {
+        let poly_1 = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+        let poly_2 = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+        let initial = Polynomial::from_container(
+            generator
+                .random_uniform_tensor::<T>(polynomial_size.0)
+                .into_container(),
+        );
+
+        // compute the expected result with the schoolbook
+        let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+        expected.fill_with_wrapping_mul(&poly_1, &poly_2);
+        expected.update_with_wrapping_add(&initial);
+
+        let mut accumulated = initial.clone();
+        accumulated.update_with_wrapping_add_karatsuba_mul(&poly_1, &poly_2);
+
+        assert_eq!(&accumulated, &expected);
+    }
+}
+
+/// test the sign flip of the coefficients whose degrees sum to the polynomial size or more
+fn test_update_with_wrapping_add_karatsuba_mul_wrap<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(64);
+    // X^(N-1) * X^2 = X^(N+1) = -X
+    let mut lhs = Polynomial::allocate(T::ZERO, polynomial_size);
+    *lhs.get_mut_monomial(MonomialDegree(polynomial_size.0 - 1))
+        .get_mut_coefficient() = T::ONE;
+    let mut rhs = Polynomial::allocate(T::ZERO, polynomial_size);
+    *rhs.get_mut_monomial(MonomialDegree(2))
+        .get_mut_coefficient() = T::ONE;
+
+    let mut res = Polynomial::allocate(T::ZERO, polynomial_size);
+    res.update_with_wrapping_add_karatsuba_mul(&lhs, &rhs);
+
+    let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+    *expected
+        .get_mut_monomial(MonomialDegree(1))
+        .get_mut_coefficient() = T::ZERO.wrapping_sub(T::ONE);
+    assert_eq!(&res, &expected);
+}
+
+/// test that X^(N-1) * X wraps to -1
+fn test_negacyclic_mul_wrap<T: UnsignedTorus>() {
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 2048) + 2);
+
+    let mut lhs = Polynomial::allocate(T::ZERO, polynomial_size);
+    *lhs.get_mut_monomial(MonomialDegree(polynomial_size.0 - 1))
+        .get_mut_coefficient() = T::ONE;
+    let mut rhs = Polynomial::allocate(T::ZERO, polynomial_size);
+    *rhs.get_mut_monomial(MonomialDegree(1))
+        .get_mut_coefficient() = T::ONE;
+
+    let mut res = Polynomial::allocate(T::ONE, polynomial_size);
+    res.fill_with_wrapping_mul(&lhs, &rhs);
+
+    let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+    *expected
+        .get_mut_monomial(MonomialDegree(0))
+        .get_mut_coefficient() = T::ZERO.wrapping_sub(T::ONE);
+    assert_eq!(&res, &expected);
+}
+
+/// test that multiplying or dividing by X^N negates every coefficient, and that multiplying by
+/// X^2N is the identity
+fn test_monic_monomial_full_rotation<T: UnsignedTorus>() {
+    let mut rng = rand::thread_rng();
+    let mut generator = new_random_generator();
+    let polynomial_size = (rng.gen::<usize>() % 2048) + 1;
+
+    let poly = Polynomial::from_container(
+        generator
+            .random_uniform_tensor::<T>(polynomial_size)
+            .into_container(),
+    );
+    let mut negated = poly.clone();
+    negated
+        .coefficient_iter_mut()
+        .for_each(|a| *a = a.wrapping_neg());
+
+    let mut mul = poly.clone();
+    mul.update_with_wrapping_monic_monomial_mul(MonomialDegree(polynomial_size));
+    assert_eq!(&mul, &negated);
+
+    let mut div = poly.clone();
+    div.update_with_wrapping_monic_monomial_div(MonomialDegree(polynomial_size));
+    assert_eq!(&div, &negated);
+
+    let mut identity = poly.clone();
+    identity.update_with_wrapping_monic_monomial_mul(MonomialDegree(2 * polynomial_size));
+    assert_eq!(&identity, &poly);
+}
+
 #[test]
 pub fn test_multiply_divide_unit_monomial_u32() {
     test_multiply_divide_unit_monomial::<u32>()
@@ -113,3 +223,57 @@ pub fn test_multiply_karatsuba_u32() {
 pub fn test_multiply_karatsuba_u64() {
     test_multiply_karatsuba::<u64>()
 }
+
+#[test]
+pub fn test_update_with_wrapping_add_karatsuba_mul_u32() {
+    test_update_with_wrapping_add_karatsuba_mul::<u32>()
+}
+
+#[test]
+pub fn test_update_with_wrapping_add_karatsuba_mul_u64() {
+    test_update_with_wrapping_add_karatsuba_mul::<u64>()
+}
+
+#[test]
+pub fn test_update_with_wrapping_add_karatsuba_mul_wrap_u32() {
+    test_update_with_wrapping_add_karatsuba_mul_wrap::<u32>()
+}
+
+#[test]
+pub fn test_update_with_wrapping_add_karatsuba_mul_wrap_u64() {
+    test_update_with_wrapping_add_karatsuba_mul_wrap::<u64>()
+}
+
+#[test]
+pub fn test_negacyclic_mul_wrap_u32() {
+    test_negacyclic_mul_wrap::<u32>()
+}
+
+#[test]
+pub fn test_negacyclic_mul_wrap_u64() {
+    test_negacyclic_mul_wrap::<u64>()
+}
+
+#[test]
+pub fn test_monic_monomial_full_rotation_u32() {
+    test_monic_monomial_full_rotation::<u32>()
+}
+
+#[test]
+pub fn test_monic_monomial_full_rotation_u64() {
+    test_monic_monomial_full_rotation::<u64>()
+}
+
+#[test]
+#[should_panic(expected = "Tried to access the monomial of degree 5 of a polynomial of size 5.")]
+pub fn test_get_monomial_out_of_range() {
+    let poly = Polynomial::from_container(vec![0u32; 5]);
+    let _ = poly.get_monomial(MonomialDegree(5));
+}
+
+#[test]
+#[should_panic(expected = "Tried to access the monomial of degree 8 of a polynomial of size 5.")]
+pub fn test_get_mut_monomial_out_of_range() {
+    let mut poly = Polynomial::from_container(vec![0u32; 5]);
+    let _ = poly.get_mut_monomial(MonomialDegree(8));
+}
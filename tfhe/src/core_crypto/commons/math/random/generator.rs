@@ -1,6 +1,6 @@
 use crate::core_crypto::commons::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb, UniformTernary,
-    UniformWithZeros,
+    Gaussian, RandomGenerable, TUniform, Uniform, UniformBinary, UniformLsb, UniformMsb,
+    UniformTernary, UniformWithZeros,
 };
 use crate::core_crypto::commons::math::tensor::{AsMutSlice, AsMutTensor, Tensor};
 use crate::core_crypto::commons::numeric::{FloatingPoint, Numeric};
@@ -323,6 +323,71 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Scalar::generate_tensor(self, UniformTernary, size)
     }
 
+    /// Generates a random value following the TUniform distribution of bound $2^b$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let random: u32 = generator.random_tuniform(3);
+    /// assert!(random <= 8 || random >= 8_u32.wrapping_neg());
+    /// ```
+    pub fn random_tuniform<Scalar: RandomGenerable<TUniform>>(
+        &mut self,
+        bound_log2: usize,
+    ) -> Scalar {
+        Scalar::generate_one(self, TUniform { bound_log2 })
+    }
+
+    /// Fills an `AsMutTensor` value with random values following the TUniform distribution of
+    /// bound $2^b$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut tensor = Tensor::allocate(0 as u64, 100);
+    /// generator.fill_tensor_with_random_tuniform(&mut tensor, 10);
+    /// ```
+    pub fn fill_tensor_with_random_tuniform<Scalar, Tensorable>(
+        &mut self,
+        output: &mut Tensorable,
+        bound_log2: usize,
+    ) where
+        Scalar: RandomGenerable<TUniform>,
+        Tensorable: AsMutTensor<Element = Scalar>,
+    {
+        Scalar::fill_tensor(self, TUniform { bound_log2 }, output);
+    }
+
+    /// Generates a tensor of random values following the TUniform distribution of bound $2^b$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let t: Tensor<Vec<u64>> = generator.random_tuniform_tensor(10, 20);
+    /// assert_eq!(t.len(), 10);
+    /// ```
+    pub fn random_tuniform_tensor<Scalar: RandomGenerable<TUniform>>(
+        &mut self,
+        size: usize,
+        bound_log2: usize,
+    ) -> Tensor<Vec<Scalar>> {
+        Scalar::generate_tensor(self, TUniform { bound_log2 }, size)
+    }
+
     /// Generates an unsigned integer whose n least significant bits are uniformly random, and the
     /// other bits are zero.
     ///
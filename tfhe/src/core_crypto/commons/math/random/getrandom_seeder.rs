@@ -0,0 +1,48 @@
+use super::{Seed, Seeder};
+
+/// A seeder drawing its seeds from the entropy source of the operating system.
+///
+/// The seeds are read with the `getrandom` crate, which supports most targets, from servers to
+/// browsers, which makes this seeder a portable default when no hardware seeder is available.
+///
+/// # Note
+///
+/// This seeder is hidden behind the "getrandom-seeder" feature gate.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::math::random::{GetRandomSeeder, Seeder};
+/// assert!(GetRandomSeeder::is_available());
+/// let mut seeder = GetRandomSeeder;
+/// let _seed = seeder.seed();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GetRandomSeeder;
+
+impl Seeder for GetRandomSeeder {
+    fn seed(&mut self) -> Seed {
+        let mut buffer = [0u8; 16];
+        getrandom::getrandom(&mut buffer)
+            .expect("Failed to read a seed from the entropy source of the operating system");
+        Seed(u128::from_le_bytes(buffer))
+    }
+
+    fn is_available() -> bool {
+        getrandom::getrandom(&mut [0u8; 1]).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consecutive_seeds_differ() {
+        assert!(GetRandomSeeder::is_available());
+        let mut seeder = GetRandomSeeder;
+        let first = seeder.seed();
+        let second = seeder.seed();
+        assert_ne!(first.0, second.0);
+    }
+}
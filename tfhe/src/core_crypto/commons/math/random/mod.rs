@@ -18,6 +18,9 @@ use crate::core_crypto::commons::numeric::FloatingPoint;
 
 pub use gaussian::*;
 pub use generator::*;
+#[cfg(feature = "getrandom-seeder")]
+pub use getrandom_seeder::*;
+pub use tuniform::*;
 pub use uniform::*;
 pub use uniform_binary::*;
 pub use uniform_lsb::*;
@@ -30,6 +33,9 @@ mod tests;
 
 mod gaussian;
 mod generator;
+#[cfg(feature = "getrandom-seeder")]
+mod getrandom_seeder;
+mod tuniform;
 mod uniform;
 mod uniform_binary;
 mod uniform_lsb;
@@ -79,6 +85,7 @@ mod seal {
     impl Sealed for super::UniformWithZeros {}
     impl Sealed for super::UniformBinary {}
     impl Sealed for super::UniformTernary {}
+    impl Sealed for super::TUniform {}
     impl<T: FloatingPoint> Sealed for super::Gaussian<T> {}
 }
 impl Distribution for Uniform {}
@@ -87,4 +94,5 @@ impl Distribution for UniformLsb {}
 impl Distribution for UniformWithZeros {}
 impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
+impl Distribution for TUniform {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
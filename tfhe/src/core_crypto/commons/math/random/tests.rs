@@ -4,6 +4,9 @@ use crate::core_crypto::commons::math::tensor::Tensor;
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::test_tools::*;
 
+use super::{RandomGenerable, RandomGenerator, TUniform};
+use concrete_csprng::generators::SoftwareRandomGenerator;
+
 fn test_normal_random<T: UnsignedTorus>() {
     //! test if the normal random generation with std_dev is below 3*std_dev (99.7%)
 
@@ -84,3 +87,93 @@ fn test_distribution<T: UnsignedTorus>() {
 // fn test_distribution_u64() {
 //     test_distribution::<u64>();
 // }
+
+#[test]
+fn test_deterministic_seeder_reproducible() {
+    use crate::core_crypto::commons::crypto::secret::generators::DeterministicSeeder;
+    use crate::core_crypto::commons::math::random::{Seed, Seeder};
+    use std::collections::HashSet;
+
+    let base = random_seed();
+    let mut first = DeterministicSeeder::<SoftwareRandomGenerator>::new(base);
+    let mut second = DeterministicSeeder::<SoftwareRandomGenerator>::new(base);
+    let mut other = DeterministicSeeder::<SoftwareRandomGenerator>::new(Seed(base.0 ^ 1));
+    let mut seen = HashSet::new();
+    for _ in 0..1000 {
+        let seed = first.seed();
+        assert_eq!(seed, second.seed());
+        assert_ne!(seed, other.seed());
+        assert!(seen.insert(seed.0));
+    }
+    assert!(DeterministicSeeder::<SoftwareRandomGenerator>::is_available());
+}
+
+fn test_tuniform_variance<T: UnsignedTorus + RandomGenerable<TUniform>>() {
+    let mut generator = new_random_generator();
+    let k = 1_000_000;
+    for bound_log2 in [0, 1, 5, 10] {
+        let samples: Tensor<Vec<T>> = generator.random_tuniform_tensor(k, bound_log2);
+        let bound = (1_i128 << bound_log2) as f64;
+        let mut sum = 0.;
+        let mut sum_squares = 0.;
+        for sample in samples.iter() {
+            let value: f64 = if *sample > T::MAX >> 1 {
+                let opposite: f64 = sample.wrapping_neg().cast_into();
+                -opposite
+            } else {
+                (*sample).cast_into()
+            };
+            assert!(
+                value.abs() <= bound,
+                "{value} is out of [-{bound}, {bound}]"
+            );
+            sum += value;
+            sum_squares += value * value;
+        }
+        let mean = sum / k as f64;
+        let variance = sum_squares / k as f64 - mean * mean;
+        let expected = (2_f64.powi(2 * bound_log2 as i32 + 1) + 1.) / 6.;
+        assert!(
+            (variance - expected).abs() / expected < 0.02,
+            "empirical variance {variance} does not match expected variance {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_tuniform_variance_u32() {
+    test_tuniform_variance::<u32>();
+}
+
+#[test]
+fn test_tuniform_variance_u64() {
+    test_tuniform_variance::<u64>();
+}
+
+fn test_fill_tensor_with_random_uniform_matches_loop<T: UnsignedTorus>() {
+    let seed = random_seed();
+    let mut bulk_generator = RandomGenerator::<SoftwareRandomGenerator>::new(seed);
+    let mut loop_generator = RandomGenerator::<SoftwareRandomGenerator>::new(seed);
+
+    // The bulk fill is split in two calls of different sizes, to check that the output only
+    // depends on the byte stream of the generator.
+    let mut bulk = Tensor::allocate(T::ZERO, 1000);
+    bulk_generator.fill_tensor_with_random_uniform(&mut bulk.get_sub_mut(..333));
+    bulk_generator.fill_tensor_with_random_uniform(&mut bulk.get_sub_mut(333..));
+
+    let mut looped = Tensor::allocate(T::ZERO, 1000);
+    for element in looped.iter_mut() {
+        *element = loop_generator.random_uniform();
+    }
+    assert_eq!(bulk, looped);
+}
+
+#[test]
+fn test_fill_tensor_with_random_uniform_matches_loop_u32() {
+    test_fill_tensor_with_random_uniform_matches_loop::<u32>();
+}
+
+#[test]
+fn test_fill_tensor_with_random_uniform_matches_loop_u64() {
+    test_fill_tensor_with_random_uniform_matches_loop::<u64>();
+}
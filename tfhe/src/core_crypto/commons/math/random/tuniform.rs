@@ -0,0 +1,44 @@
+use super::*;
+use crate::core_crypto::commons::numeric::Numeric;
+
+/// A distribution type representing the TUniform sampling of unsigned integer types.
+///
+/// The values are sampled in $[-2^b, 2^b]$ (and represented modulo the integer size), with
+/// $b$ the `bound_log2` field. Every value in the open interval $]-2^b, 2^b[$ is drawn with
+/// probability $2^{-(b+1)}$, and the two bounds with probability $2^{-(b+2)}$. The variance of
+/// the distribution is $(2^{2b+1} + 1) / 6$.
+///
+/// The sampling draws $b + 2$ random bits $r$, and outputs $\lfloor r / 2 \rfloor + (r \bmod 2)
+/// - 2^b$, which requires $b + 2$ to be at most the bit size of the integer type.
+#[derive(Copy, Clone)]
+pub struct TUniform {
+    /// The base 2 logarithm of the bound of the distribution.
+    pub bound_log2: usize,
+}
+
+macro_rules! implement_tuniform {
+    ($T:ty) => {
+        impl RandomGenerable<TUniform> for $T {
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                TUniform { bound_log2 }: TUniform,
+            ) -> Self {
+                assert!(
+                    bound_log2 + 2 <= <$T as Numeric>::BITS,
+                    "The TUniform bound 2^{} cannot be sampled with {}-bit integers",
+                    bound_log2,
+                    <$T as Numeric>::BITS
+                );
+                let bits = <$T>::generate_one(generator, Uniform)
+                    >> (<$T as Numeric>::BITS - (bound_log2 + 2));
+                ((bits >> 1) + (bits & 1)).wrapping_sub(1 << bound_log2)
+            }
+        }
+    };
+}
+
+implement_tuniform!(u8);
+implement_tuniform!(u16);
+implement_tuniform!(u32);
+implement_tuniform!(u64);
+implement_tuniform!(u128);
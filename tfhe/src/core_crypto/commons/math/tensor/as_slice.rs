@@ -53,6 +53,14 @@ impl<Element> AsRefSlice for aligned_vec::AVec<Element> {
     }
 }
 
+#[cfg(feature = "arrayvec")]
+impl<Element, const N: usize> AsRefSlice for arrayvec::ArrayVec<Element, N> {
+    type Element = Element;
+    fn as_slice(&self) -> &[Element] {
+        self.as_slice()
+    }
+}
+
 impl<Element> AsRefSlice for [Element; 1] {
     type Element = Element;
     fn as_slice(&self) -> &[Element] {
@@ -100,6 +108,14 @@ impl<Element> AsMutSlice for aligned_vec::AVec<Element> {
     }
 }
 
+#[cfg(feature = "arrayvec")]
+impl<Element, const N: usize> AsMutSlice for arrayvec::ArrayVec<Element, N> {
+    type Element = Element;
+    fn as_mut_slice(&mut self) -> &mut [Element] {
+        self.as_mut_slice()
+    }
+}
+
 impl<Element> AsMutSlice for [Element; 1] {
     type Element = Element;
     fn as_mut_slice(&mut self) -> &mut [Element] {
@@ -0,0 +1,158 @@
+use super::{AsRefSlice, Container, Tensor};
+use std::fs::File;
+use std::io::{Error, ErrorKind};
+use std::marker::PhantomData;
+
+/// A marker trait for the element types which can be read from the raw bytes of a file.
+///
+/// # Safety
+///
+/// This trait must only be implemented for types for which any sequence of bytes of the right
+/// size is a valid value, and which do not contain any padding or pointer.
+pub unsafe trait Pod: Copy {}
+
+macro_rules! implement_pod {
+    ($($Type: ty),*) => {
+        $(unsafe impl Pod for $Type {})*
+    };
+}
+
+implement_pod!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// A read-only container whose elements are read from a memory-mapped file.
+///
+/// The content of the file is only loaded in memory when its elements are accessed, and a
+/// `Tensor<MmapContainer<T>>` can be built from it without copying the file into a `Vec`.
+///
+/// # Note
+///
+/// This type is hidden behind the "mmap" feature gate.
+#[derive(Debug)]
+pub struct MmapContainer<T> {
+    mmap: memmap2::Mmap,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Pod> MmapContainer<T> {
+    /// Maps a file in memory, interpreting its bytes as elements of type `T`, in native endianness.
+    ///
+    /// An error is returned if the file can not be mapped, or if its size is not a multiple of the
+    /// size of `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, by this process or another one, while it is mapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use tfhe::core_crypto::commons::math::tensor::{MmapContainer, Tensor};
+    /// let path = std::env::temp_dir().join("tfhe_mmap_container_doctest");
+    /// let values: Vec<u64> = vec![1, 2, 3, 4];
+    /// let mut file = std::fs::File::create(&path).unwrap();
+    /// for value in values.iter() {
+    ///     file.write_all(&value.to_ne_bytes()).unwrap();
+    /// }
+    /// drop(file);
+    ///
+    /// let file = std::fs::File::open(&path).unwrap();
+    /// let container = unsafe { MmapContainer::<u64>::map(&file) }.unwrap();
+    /// let tensor = Tensor::from_container(container);
+    /// assert_eq!(tensor.len(), 4);
+    /// assert_eq!(tensor.iter().sum::<u64>(), 10);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub unsafe fn map(file: &File) -> Result<MmapContainer<T>, Error> {
+        let mmap = memmap2::Mmap::map(file)?;
+        if mmap.len() % std::mem::size_of::<T>() != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "The file size ({} bytes) is not a multiple of the element size ({} bytes).",
+                    mmap.len(),
+                    std::mem::size_of::<T>()
+                ),
+            ));
+        }
+        // Mappings are page aligned, which is enough for any of the `Pod` types.
+        debug_assert_eq!(mmap.as_ptr() as usize % std::mem::align_of::<T>(), 0);
+        Ok(MmapContainer {
+            mmap,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Pod> AsRef<[T]> for MmapContainer<T> {
+    fn as_ref(&self) -> &[T] {
+        let len = self.mmap.len() / std::mem::size_of::<T>();
+        // SAFETY: the mapping is aligned for `T` and contains `len` elements, which are all valid
+        // values as `T` is `Pod`.
+        unsafe { std::slice::from_raw_parts(self.mmap.as_ptr() as *const T, len) }
+    }
+}
+
+impl<T: Pod> Container for MmapContainer<T> {
+    type Element = T;
+}
+
+impl<T: Pod> AsRefSlice for MmapContainer<T> {
+    type Element = T;
+    fn as_slice(&self) -> &[T] {
+        self.as_ref()
+    }
+}
+
+/// A tensor whose elements are read from a memory-mapped file.
+///
+/// The content of the file is only loaded in memory when its elements are accessed, which
+/// allows to work with large keys without reading them up front.
+///
+/// # Note
+///
+/// This type is hidden behind the "mmap" feature gate.
+#[derive(Debug)]
+pub struct MappedTensor<T> {
+    container: MmapContainer<T>,
+}
+
+impl<T: Pod> MappedTensor<T> {
+    /// Maps a file in memory, like [`MmapContainer::map`] which describes the errors returned.
+    ///
+    /// # Safety
+    ///
+    /// See [`MmapContainer::map`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use tfhe::core_crypto::commons::math::tensor::MappedTensor;
+    /// let path = std::env::temp_dir().join("tfhe_mapped_tensor_doctest");
+    /// let values: Vec<u32> = vec![1, 2, 3, 4];
+    /// let mut file = std::fs::File::create(&path).unwrap();
+    /// for value in values.iter() {
+    ///     file.write_all(&value.to_ne_bytes()).unwrap();
+    /// }
+    /// drop(file);
+    ///
+    /// let file = std::fs::File::open(&path).unwrap();
+    /// let mapped = unsafe { MappedTensor::<u32>::map(&file) }.unwrap();
+    /// assert_eq!(mapped.as_view().len(), 4);
+    /// assert_eq!(*mapped.as_view().get_element(2), 3);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub unsafe fn map(file: &File) -> Result<MappedTensor<T>, Error> {
+        Ok(MappedTensor {
+            container: MmapContainer::map(file)?,
+        })
+    }
+
+    /// Returns a tensor view over the mapped elements.
+    ///
+    /// See [`MappedTensor::map`] for an example.
+    pub fn as_view(&self) -> Tensor<&[T]> {
+        Tensor::from_container(self.container.as_ref())
+    }
+}
@@ -165,6 +165,23 @@ macro_rules! ck_dim_div {
 }
 pub(crate) use ck_dim_div;
 
+macro_rules! ck_dim_pow2 {
+    ($($size: expr),* ) => {
+        $(
+            let func = $crate::core_crypto::commons::math::tensor::current_func_path!();
+            debug_assert!(
+                $size.is_power_of_two(),
+                "Called operation {} on tensors of incompatible size. {} (={:?}) is not a power of \
+                two.",
+                func,
+                stringify!($size),
+                $size,
+            );
+        )*
+    };
+}
+pub(crate) use ck_dim_pow2;
+
 #[cfg(test)]
 mod tests;
 
@@ -184,6 +201,11 @@ pub use as_tensor::*;
 mod into_tensor;
 pub use into_tensor::*;
 
+#[cfg(feature = "mmap")]
+mod mapped;
+#[cfg(feature = "mmap")]
+pub use mapped::*;
+
 pub trait Container: AsRef<[Self::Element]> {
     type Element;
 
@@ -218,6 +240,25 @@ impl<T> ContainerOwned for aligned_vec::ABox<[T]> {
     }
 }
 
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> Container for arrayvec::ArrayVec<T, N> {
+    type Element = T;
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> ContainerOwned for arrayvec::ArrayVec<T, N> {
+    fn collect<I: Iterator<Item = Self::Element>>(iter: I) -> Self {
+        let mut array = arrayvec::ArrayVec::new();
+        for element in iter {
+            assert!(
+                array.try_push(element).is_ok(),
+                "Tried to collect more than {N} elements in an ArrayVec of capacity {N}"
+            );
+        }
+        array
+    }
+}
+
 impl<'a, T> Container for &'a [T] {
     type Element = T;
 }
@@ -230,6 +271,7 @@ pub trait Split: Sized {
     type Chunks: DoubleEndedIterator<Item = Self> + ExactSizeIterator<Item = Self>;
 
     fn into_chunks(self, chunk_size: usize) -> Self::Chunks;
+    fn into_chunks_remainder(self, chunk_size: usize) -> (Self::Chunks, Self);
     fn split_into(self, chunk_count: usize) -> Self::Chunks;
     fn split_at(self, mid: usize) -> (Self, Self);
 }
@@ -243,6 +285,12 @@ impl<'a, T> Split for &'a [T] {
         self.chunks_exact(chunk_size)
     }
     #[inline]
+    fn into_chunks_remainder(self, chunk_size: usize) -> (Self::Chunks, Self) {
+        let chunks = self.chunks_exact(chunk_size);
+        let remainder = chunks.remainder();
+        (chunks, remainder)
+    }
+    #[inline]
     fn split_into(self, chunk_count: usize) -> Self::Chunks {
         if chunk_count == 0 {
             debug_assert_eq!(self.len(), 0);
@@ -267,6 +315,12 @@ impl<'a, T> Split for &'a mut [T] {
         self.chunks_exact_mut(chunk_size)
     }
     #[inline]
+    fn into_chunks_remainder(self, chunk_size: usize) -> (Self::Chunks, Self) {
+        let mid = self.len() - self.len() % chunk_size;
+        let (exact, remainder) = self.split_at_mut(mid);
+        (exact.chunks_exact_mut(chunk_size), remainder)
+    }
+    #[inline]
     fn split_into(self, chunk_count: usize) -> Self::Chunks {
         if chunk_count == 0 {
             debug_assert_eq!(self.len(), 0);
@@ -1,3 +1,5 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::iter::FromIterator;
 use std::ops::{
     BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, ShlAssign, Shr, ShrAssign,
@@ -9,11 +11,13 @@ use rayon::{iter::IndexedParallelIterator, prelude::*};
 #[cfg(feature = "__commons_serialization")]
 use serde::{Deserialize, Serialize};
 
-use crate::core_crypto::commons::numeric::{CastFrom, UnsignedInteger};
+use crate::core_crypto::commons::math::torus::IntoTorus;
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto, Numeric, UnsignedInteger};
+use crate::core_crypto::prelude::CiphertextModulusLog;
 
 use crate::core_crypto::commons::utils::zip;
 
-use super::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use super::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Split};
 
 /// A generic type to perform operations on collections of scalar values.
 ///
@@ -50,6 +54,34 @@ impl<Element> Tensor<Vec<Element>> {
     }
 }
 
+impl Tensor<Vec<u8>> {
+    /// Unpacks the `len` first bits of `bytes` into a tensor of zeros and ones.
+    ///
+    /// This is the inverse of [`pack_bits`](`Tensor::pack_bits`): the bits of each byte are read
+    /// from the least significant to the most significant one.
+    ///
+    /// # Note:
+    ///
+    /// Panics if `bytes` does not contain exactly `(len + 7) / 8` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::unpack_bits(&[0b0110_1101, 0b0000_0010], 10);
+    /// assert_eq!(tensor.as_container(), &vec![1u8, 0, 1, 1, 0, 1, 1, 0, 0, 1]);
+    /// ```
+    pub fn unpack_bits(bytes: &[u8], len: usize) -> Self {
+        assert_eq!(
+            bytes.len(),
+            (len + 7) / 8,
+            "{} bytes can not hold exactly {len} bits",
+            bytes.len()
+        );
+        (0..len).map(|i| (bytes[i / 8] >> (i % 8)) & 1).collect()
+    }
+}
+
 macro_rules! fill_with {
     ($Trait:ident, $name: ident, $($func:tt)*) => {
             pub fn $name<Lhs, Rhs>(
@@ -153,7 +185,63 @@ macro_rules! update_with_wrapping_scalar {
     };
 }
 
+/// The error which can occur when building a tensor from a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TensorError {
+    LengthMismatch { expected: usize, got: usize },
+}
+
+impl Display for TensorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TensorError::LengthMismatch { expected, got } => {
+                write!(
+                    f,
+                    "The container has length {got}, while length {expected} was expected."
+                )
+            }
+        }
+    }
+}
+
+impl Error for TensorError {}
+
 impl<Container> Tensor<Container> {
+    /// Creates a new tensor from a container, checking that it has the expected length.
+    ///
+    /// Contrarily to the dimension checks performed by higher-level constructors, this check is
+    /// also performed in release builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::{Tensor, TensorError};
+    /// let tensor = Tensor::try_from_container(vec![9 as u8; 1000], 1000).unwrap();
+    /// assert_eq!(tensor.len(), 1000);
+    /// let error = Tensor::try_from_container(vec![9 as u8; 999], 1000).unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     TensorError::LengthMismatch {
+    ///         expected: 1000,
+    ///         got: 999
+    ///     }
+    /// );
+    /// ```
+    pub fn try_from_container(cont: Container, expected_len: usize) -> Result<Self, TensorError>
+    where
+        Container: AsRefSlice,
+    {
+        let len = cont.as_slice().len();
+        if len == expected_len {
+            Ok(Tensor(cont))
+        } else {
+            Err(TensorError::LengthMismatch {
+                expected: expected_len,
+                got: len,
+            })
+        }
+    }
+
     /// Creates a new tensor from a container.
     ///
     /// # Example
@@ -358,6 +446,118 @@ impl<Container> Tensor<Container> {
         self.as_slice().chunks(size).map(Tensor::from_container)
     }
 
+    /// Returns an iterator over sub tensors `Tensor<&[Scalar]>` of length `size`, along with the
+    /// remaining elements.
+    ///
+    /// Contrary to [`subtensor_iter`](`Tensor::subtensor_iter`), the length of the sub-tensors
+    /// does not need to divide the size of the tensor: the last `len % size` elements are
+    /// returned in a separate tensor, which is empty when the size divides evenly.
+    ///
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container((0..10).collect::<Vec<u8>>());
+    /// let (subtensors, remainder) = tensor.subtensor_iter_with_remainder(4);
+    /// assert_eq!(subtensors.count(), 2);
+    /// assert_eq!(remainder.as_container(), &[8, 9]);
+    ///
+    /// let (subtensors, remainder) = tensor.subtensor_iter_with_remainder(5);
+    /// for sub in subtensors {
+    ///     assert_eq!(sub.len(), 5);
+    /// }
+    /// assert!(remainder.is_empty());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn subtensor_iter_with_remainder(
+        &self,
+        size: usize,
+    ) -> (
+        impl DoubleEndedIterator<Item = Tensor<&[<Self as AsRefSlice>::Element]>> + ExactSizeIterator,
+        Tensor<&[<Self as AsRefSlice>::Element]>,
+    )
+    where
+        Self: AsRefSlice,
+    {
+        let (chunks, remainder) = self.as_slice().into_chunks_remainder(size);
+        (
+            chunks.map(Tensor::from_container),
+            Tensor::from_container(remainder),
+        )
+    }
+
+    /// Applies `f` to each sub tensor `Tensor<&[Scalar]>` of length `chunk_size`, and collects
+    /// the outputs in a new tensor.
+    ///
+    /// # Note:
+    /// The length of the sub-tensors must divide the size of the tensor.
+    ///
+    /// # Example:
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![1u8, 1, 2, 2]);
+    /// let sums = tensor.chunks_map_collect(2, |chunk| chunk.iter().sum::<u8>());
+    /// assert_eq!(sums, Tensor::from_container(vec![2u8, 4]));
+    /// ```
+    pub fn chunks_map_collect<O, F>(&self, chunk_size: usize, f: F) -> Tensor<Vec<O>>
+    where
+        Self: AsRefSlice,
+        F: FnMut(Tensor<&[<Self as AsRefSlice>::Element]>) -> O,
+    {
+        ck_dim_div!(self.len() => chunk_size);
+        Tensor::from_container(
+            self.as_slice()
+                .chunks_exact(chunk_size)
+                .map(Tensor::from_container)
+                .map(f)
+                .collect(),
+        )
+    }
+
+    /// Splits the tensor into a prefix, a middle of SIMD vectors of `LANES` elements, and a
+    /// suffix, the same way as [`slice::as_simd`].
+    ///
+    /// The middle is aligned for the SIMD vectors, and the prefix and suffix contain the elements
+    /// which could not be part of it.
+    ///
+    /// # Note:
+    /// This method is hidden behind the "nightly-simd" feature gate.
+    ///
+    /// # Example:
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container((0..33).collect::<Vec<u64>>());
+    /// let (prefix, middle, suffix) = tensor.as_simd_chunks::<4>();
+    /// assert_eq!(prefix.len() + 4 * middle.len() + suffix.len(), 33);
+    /// let sum: u64 = prefix.iter().sum::<u64>()
+    ///     + middle
+    ///         .iter()
+    ///         .map(|vector| vector.to_array().iter().sum::<u64>())
+    ///         .sum::<u64>()
+    ///     + suffix.iter().sum::<u64>();
+    /// assert_eq!(sum, 32 * 33 / 2);
+    /// ```
+    #[cfg(feature = "nightly-simd")]
+    #[allow(clippy::type_complexity)]
+    pub fn as_simd_chunks<const LANES: usize>(
+        &self,
+    ) -> (
+        Tensor<&[<Self as AsRefSlice>::Element]>,
+        &[std::simd::Simd<<Self as AsRefSlice>::Element, LANES>],
+        Tensor<&[<Self as AsRefSlice>::Element]>,
+    )
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: std::simd::SimdElement,
+    {
+        let (prefix, middle, suffix) = self.as_slice().as_simd::<LANES>();
+        (
+            Tensor::from_container(prefix),
+            middle,
+            Tensor::from_container(suffix),
+        )
+    }
+
     /// Returns a parallel iterator over sub tensors `Tensor<&[Scalar]>`.
     ///
     /// # Note:
@@ -382,7 +582,7 @@ impl<Container> Tensor<Container> {
         Self: AsRefSlice,
         <Self as AsRefSlice>::Element: Sync,
     {
-        debug_assert!(self.as_slice().len() % size == 0, "Uneven chunks size");
+        ck_dim_div!(self.len() => size);
         self.as_slice().par_chunks(size).map(Tensor::from_container)
     }
 
@@ -642,6 +842,71 @@ impl<Container> Tensor<Container> {
             .unwrap()
     }
 
+    /// Splits the tensor in two ref tensors, the first one containing the values with indices in
+    /// `[0, mid)`, and the second one the values with indices in `[mid, len)`.
+    ///
+    /// # Note:
+    ///
+    /// Panics if `mid` is greater than the length of the tensor.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container((0..10u8).collect::<Vec<_>>());
+    /// let (left, right) = tensor.split_at(3);
+    /// assert_eq!(left, Tensor::from_container(&[0u8, 1, 2][..]));
+    /// assert_eq!(right, Tensor::from_container(&[3u8, 4, 5, 6, 7, 8, 9][..]));
+    /// ```
+    pub fn split_at<Element>(&self, mid: usize) -> (Tensor<&[Element]>, Tensor<&[Element]>)
+    where
+        Self: AsRefSlice<Element = Element>,
+    {
+        assert!(
+            mid <= self.len(),
+            "Tried to split a tensor of length {} at index {}.",
+            self.len(),
+            mid
+        );
+        let (left, right) = self.as_slice().split_at(mid);
+        (Tensor(left), Tensor(right))
+    }
+
+    /// Splits the tensor in two mut tensors, the first one containing the values with indices in
+    /// `[0, mid)`, and the second one the values with indices in `[mid, len)`.
+    ///
+    /// # Note:
+    ///
+    /// Panics if `mid` is greater than the length of the tensor.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut tensor = Tensor::allocate(9 as u8, 10);
+    /// let (mut left, mut right) = tensor.split_at_mut(3);
+    /// left.fill_with_element(1);
+    /// right.fill_with_element(2);
+    /// assert_eq!(*tensor.get_element(2), 1);
+    /// assert_eq!(*tensor.get_element(3), 2);
+    /// ```
+    pub fn split_at_mut<Element>(
+        &mut self,
+        mid: usize,
+    ) -> (Tensor<&mut [Element]>, Tensor<&mut [Element]>)
+    where
+        Self: AsMutSlice<Element = Element>,
+    {
+        assert!(
+            mid <= self.len(),
+            "Tried to split a tensor of length {} at index {}.",
+            self.len(),
+            mid
+        );
+        let (left, right) = self.as_mut_slice().split_at_mut(mid);
+        (Tensor(left), Tensor(right))
+    }
+
     /// Returns a sub tensor from a range of indices.
     ///
     /// # Note:
@@ -852,6 +1117,96 @@ impl<Container> Tensor<Container> {
         }
     }
 
+    /// Fills a mutable tensor with the result of an element-wise operation on two other tensors of
+    /// the same size, in parallel.
+    ///
+    /// The three tensors are split on the same chunk boundaries, and each output element is only
+    /// computed from the input elements at the same index. The result is thus identical to the one
+    /// of [`fill_with_two`](`Tensor::fill_with_two`), whatever the number of threads.
+    ///
+    /// # Note:
+    /// This method is hidden behind the "__commons_parallel" feature gate.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 10000);
+    /// let t2 = Tensor::allocate(1 as u8, 10000);
+    /// let t3 = Tensor::allocate(2 as u8, 10000);
+    /// t1.par_fill_with_two(&t2, &t3, |t2, t3| t3 + t2);
+    /// for scalar in t1.iter() {
+    ///     assert_eq!(*scalar, 3);
+    /// }
+    /// ```
+    #[cfg(feature = "__commons_parallel")]
+    pub fn par_fill_with_two<Cont1, Cont2>(
+        &mut self,
+        lhs: &Tensor<Cont1>,
+        rhs: &Tensor<Cont2>,
+        ope: impl Fn(
+                &<Tensor<Cont1> as AsRefSlice>::Element,
+                &<Tensor<Cont2> as AsRefSlice>::Element,
+            ) -> <Self as AsMutSlice>::Element
+            + Sync,
+    ) where
+        Tensor<Cont1>: AsRefSlice,
+        Tensor<Cont2>: AsRefSlice,
+        Self: AsMutSlice,
+        <Tensor<Cont1> as AsRefSlice>::Element: Sync,
+        <Tensor<Cont2> as AsRefSlice>::Element: Sync,
+        <Self as AsMutSlice>::Element: Send,
+    {
+        const CHUNK_SIZE: usize = 4096;
+        ck_dim_eq!(self.len() => lhs.len());
+        ck_dim_eq!(self.len() => rhs.len());
+        self.as_mut_slice()
+            .par_chunks_mut(CHUNK_SIZE)
+            .zip(lhs.as_slice().par_chunks(CHUNK_SIZE))
+            .zip(rhs.as_slice().par_chunks(CHUNK_SIZE))
+            .for_each(|((output, lhs), rhs)| {
+                for (output_i, (lhs_i, rhs_i)) in zip!(output.iter_mut(), lhs.iter(), rhs.iter()) {
+                    *output_i = ope(lhs_i, rhs_i);
+                }
+            });
+    }
+
+    /// Fills a mutable tensor with the result of an element-wise operation on three other tensors
+    /// of the same size
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// let t2 = Tensor::allocate(3 as u8, 1000);
+    /// let t3 = Tensor::allocate(4 as u8, 1000);
+    /// let t4 = Tensor::allocate(5 as u8, 1000);
+    /// t1.fill_with_three(&t2, &t3, &t4, |a, b, c| a * b + c);
+    /// for scalar in t1.iter() {
+    ///     assert_eq!(*scalar, 17);
+    /// }
+    /// ```
+    pub fn fill_with_three<F, Cont1, Cont2, Cont3, Element1, Element2, Element3>(
+        &mut self,
+        first: &Tensor<Cont1>,
+        second: &Tensor<Cont2>,
+        third: &Tensor<Cont3>,
+        mut ope: F,
+    ) where
+        Tensor<Cont1>: AsRefSlice<Element = Element1>,
+        Tensor<Cont2>: AsRefSlice<Element = Element2>,
+        Tensor<Cont3>: AsRefSlice<Element = Element3>,
+        Self: AsMutSlice,
+        F: FnMut(&Element1, &Element2, &Element3) -> <Self as AsMutSlice>::Element,
+    {
+        ck_dim_eq!(self.len() => first.len(), second.len(), third.len());
+        for (output_i, (first_i, (second_i, third_i))) in zip!(
+            self.iter_mut(),
+            first.as_slice().iter(),
+            second.as_slice().iter(),
+            third.as_slice().iter()
+        ) {
+            *output_i = ope(first_i, second_i, third_i);
+        }
+    }
+
     /// Fills a mutable tensor with the result of an element-wise operation on one other tensor of
     /// the same size
     ///
@@ -1129,6 +1484,42 @@ impl<Container> Tensor<Container> {
     update_with_wrapping_scalar!(update_with_wrapping_scalar_div, |s, a| *s =
         s.wrapping_div(*a));
 
+    /// Adds another tensor to `self`, interpreting both as multi-limb integers stored in
+    /// little-endian order, and returns the final carry-out.
+    ///
+    /// # Example
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// // 0x01_ff_ff + 0x00_00_01 = 0x02_00_00
+    /// let mut t1 = Tensor::from_container(vec![0xffu8, 0xff, 0x01]);
+    /// let t2 = Tensor::from_container(vec![0x01u8, 0x00, 0x00]);
+    /// let carry = t1.update_with_carrying_add(&t2);
+    /// assert_eq!(t1.as_container(), &vec![0x00u8, 0x00, 0x02]);
+    /// assert!(!carry);
+    /// // 0xff_ff + 0x00_01 = 0x1_00_00
+    /// let mut t1 = Tensor::from_container(vec![0xffu8, 0xff]);
+    /// let t2 = Tensor::from_container(vec![0x01u8, 0x00]);
+    /// let carry = t1.update_with_carrying_add(&t2);
+    /// assert_eq!(t1.as_container(), &vec![0x00u8, 0x00]);
+    /// assert!(carry);
+    /// ```
+    pub fn update_with_carrying_add<Cont, Element>(&mut self, other: &Tensor<Cont>) -> bool
+    where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<Cont>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        ck_dim_eq!(self.len() => other.len());
+        let mut carry = false;
+        for (self_i, other_i) in zip!(self.iter_mut(), other.as_slice().iter()) {
+            let partial = self_i.wrapping_add(*other_i);
+            let sum = partial.wrapping_add(<Element as CastFrom<bool>>::cast_from(carry));
+            carry = partial < *self_i || sum < partial;
+            *self_i = sum;
+        }
+        carry
+    }
+
     /// Sets each value of `self` to its own wrapping opposite.
     ///
     /// # Example
@@ -1148,6 +1539,68 @@ impl<Container> Tensor<Container> {
         self.update_with(|a| *a = a.wrapping_neg());
     }
 
+    /// Replaces each value of `self` with the maximum of itself and of all the values preceding
+    /// it.
+    ///
+    /// # Example
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut tensor = Tensor::from_container(vec![1u8, 3, 2, 5, 4]);
+    /// tensor.running_max();
+    /// assert_eq!(tensor.as_container(), &vec![1u8, 3, 3, 5, 5]);
+    /// ```
+    pub fn running_max(&mut self)
+    where
+        Self: AsMutSlice,
+        <Self as AsMutSlice>::Element: Ord + Copy,
+    {
+        let mut values = self.iter_mut();
+        if let Some(first) = values.next() {
+            let mut max = *first;
+            for value in values {
+                max = max.max(*value);
+                *value = max;
+            }
+        }
+    }
+
+    /// Negates, in constant time, each value of `self` whose matching choice in `signs` is set.
+    ///
+    /// The values are always negated and then conditionally selected, so that the running time
+    /// does not depend on `signs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `signs` does not have the same length as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use subtle::Choice;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, Tensor};
+    /// let mut tensor = Tensor::from_container(vec![1u8, 2, 3, 4]);
+    /// let signs = [0, 1, 1, 0].map(Choice::from);
+    /// tensor.conditional_negate(&signs);
+    /// assert_eq!(tensor.as_slice(), &[1, 254, 253, 4]);
+    /// ```
+    #[cfg(feature = "subtle")]
+    pub fn conditional_negate(&mut self, signs: &[subtle::Choice])
+    where
+        Self: AsMutSlice,
+        <Self as AsMutSlice>::Element: UnsignedInteger + subtle::ConditionallySelectable,
+    {
+        assert_eq!(
+            self.len(),
+            signs.len(),
+            "The number of signs ({}) does not match the length of the tensor ({})",
+            signs.len(),
+            self.len()
+        );
+        for (value, sign) in self.as_mut_slice().iter_mut().zip(signs.iter()) {
+            let negated = value.wrapping_neg();
+            subtle::ConditionallySelectable::conditional_assign(value, &negated, *sign);
+        }
+    }
+
     /// Fills a mutable tensor with the result of the wrapping multiplication of elements of
     /// another tensor by an element.
     ///
@@ -1226,6 +1679,71 @@ impl<Container> Tensor<Container> {
         self.update_with_one(tensor, |s, t| *s = s.wrapping_add(t.wrapping_mul(element)));
     }
 
+    /// Updates the values of a mutable tensor by wrap-adding the element-wise wrapping product of
+    /// two other tensors, in a single pass.
+    ///
+    /// # Example
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// let t2 = Tensor::allocate(2 as u8, 1000);
+    /// let t3 = vec![125 as u8; 1000];
+    /// t1.update_with_wrapping_add_mul(&t2, &Tensor::from_container(t3.as_slice()));
+    /// for scalar in t1.iter() {
+    ///     assert_eq!(*scalar, 3);
+    /// }
+    /// ```
+    pub fn update_with_wrapping_add_mul<Cont1, Cont2, Element>(
+        &mut self,
+        lhs: &Tensor<Cont1>,
+        rhs: &Tensor<Cont2>,
+    ) where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<Cont1>: AsRefSlice<Element = Element>,
+        Tensor<Cont2>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        ck_dim_eq!(self.len() => lhs.len(), rhs.len());
+        self.update_with_two(lhs, rhs, |s, l, r| *s = s.wrapping_add(l.wrapping_mul(*r)));
+    }
+
+    /// Computes a value by folding the elements of the tensor.
+    ///
+    /// # Example
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![1u16, 2, 3, 4]);
+    /// let val = tensor.fold(0, |a, t| a + t * t);
+    /// assert_eq!(val, 30);
+    /// ```
+    pub fn fold<A, F>(&self, init: A, f: F) -> A
+    where
+        Self: AsRefSlice,
+        F: FnMut(A, &<Self as AsRefSlice>::Element) -> A,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Computes the wrapping sum of the elements of the tensor.
+    ///
+    /// # Example
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::allocate(1u8, 100);
+    /// assert_eq!(tensor.wrapping_sum(), 100);
+    /// let tensor = Tensor::allocate(1u8, 300);
+    /// assert_eq!(tensor.wrapping_sum(), 44);
+    /// ```
+    pub fn wrapping_sum(&self) -> <Self as AsRefSlice>::Element
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: UnsignedInteger,
+    {
+        self.fold(<Self as AsRefSlice>::Element::ZERO, |acc, element| {
+            acc.wrapping_add(*element)
+        })
+    }
+
     /// Computes a value by folding a tensor with another.
     ///
     /// # Example
@@ -1259,6 +1777,166 @@ impl<Container> Tensor<Container> {
             .fold(acc, |acc, (s_i, o_i)| ope(acc, s_i, o_i))
     }
 
+    /// Returns the index of the first element differing between two tensors, or `None` if the
+    /// tensors are equal.
+    ///
+    /// # Note:
+    ///
+    /// Panics if the two tensors do not have the same length.
+    ///
+    /// # Example
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let t1 = Tensor::from_container(vec![1u8, 2, 3, 4, 5]);
+    /// let t2 = Tensor::from_container(vec![1u8, 2, 3, 0, 5]);
+    /// assert_eq!(t1.first_difference(&t2), Some(3));
+    /// assert_eq!(t1.first_difference(&t1), None);
+    /// ```
+    pub fn first_difference<Cont>(&self, other: &Tensor<Cont>) -> Option<usize>
+    where
+        Self: AsRefSlice,
+        Tensor<Cont>: AsRefSlice<Element = <Self as AsRefSlice>::Element>,
+        <Self as AsRefSlice>::Element: PartialEq,
+    {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Tried to compare tensors of different lengths."
+        );
+        self.iter()
+            .zip(other.as_slice().iter())
+            .position(|(s_i, o_i)| s_i != o_i)
+    }
+
+    /// Returns a new tensor containing the torus values of the elements of the tensor, in
+    /// floating point representation.
+    ///
+    /// Each unsigned integer element is mapped to its torus value in $[0,1)$. Note that for 64
+    /// bits integers, the values closest to $1$ may be rounded to $1$ by the conversion to `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![0u32, 1 << 31, u32::MAX]);
+    /// let torus = tensor.to_f64_torus();
+    /// assert_eq!(*torus.get_element(0), 0.);
+    /// assert_eq!(*torus.get_element(1), 0.5);
+    /// assert!((*torus.get_element(2) - 1.).abs() < 1e-9);
+    /// ```
+    pub fn to_f64_torus(&self) -> Tensor<Vec<f64>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: IntoTorus<f64> + Copy,
+    {
+        self.iter().map(|element| element.into_torus()).collect()
+    }
+
+    /// Returns a new tensor containing the absolute torus distance between each element of the
+    /// tensor and zero, in floating point representation.
+    ///
+    /// The elements are interpreted as centered values, so that the values close to `MAX` are
+    /// also close to zero on the torus. The resulting distances are in $[0, 0.5]$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![0u32, 1 << 30, u32::MAX, 1 << 31]);
+    /// let distances = tensor.abs_torus();
+    /// assert_eq!(*distances.get_element(0), 0.);
+    /// assert_eq!(*distances.get_element(1), 0.25);
+    /// assert_eq!(*distances.get_element(2), 2_f64.powi(-32));
+    /// assert_eq!(*distances.get_element(3), 0.5);
+    /// ```
+    pub fn abs_torus(&self) -> Tensor<Vec<f64>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: UnsignedInteger + IntoTorus<f64>,
+    {
+        self.iter()
+            .map(|element| (*element).min(element.wrapping_neg()).into_torus())
+            .collect()
+    }
+
+    /// Returns a new tensor containing the elements switched to the modulus
+    /// $2^{\text{log\_out\_modulus}}$, using `guard_bits` extra bits for the rounding.
+    ///
+    /// The `log_out_modulus + guard_bits` most significant bits of each element are kept and the
+    /// others are discarded. The kept value is then rounded once to its `log_out_modulus` most
+    /// significant bits, to the closest value, ties to even. With `guard_bits = 0`, this amounts
+    /// to a truncation. Since the discarded bits are not looked at, an element is considered to
+    /// be a tie as soon as its guard bits read exactly one half: the fewer the guard bits, the
+    /// more elements are rounded to the even output value instead of the closest one. The
+    /// outputs are reduced modulo $2^{\text{log\_out\_modulus}}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_out_modulus` is 0, or if `log_out_modulus + guard_bits` is greater than the
+    /// number of bits of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// use tfhe::core_crypto::prelude::CiphertextModulusLog;
+    /// let tensor = Tensor::from_container(vec![0x17_ff_ff_ffu32, 0x18_00_00_00, 0xff_ff_ff_ff]);
+    /// let truncated = tensor.modulus_switch_with_guard(CiphertextModulusLog(4), 0);
+    /// assert_eq!(truncated.as_container(), &vec![1, 1, 15]);
+    /// let rounded = tensor.modulus_switch_with_guard(CiphertextModulusLog(4), 1);
+    /// assert_eq!(rounded.as_container(), &vec![1, 2, 0]);
+    ///
+    /// // 2.75 is seen as a tie with one guard bit, and rounded to 3 with two guard bits
+    /// let tensor = Tensor::from_container(vec![0x2c_00_00_00u32]);
+    /// let rounded = tensor.modulus_switch_with_guard(CiphertextModulusLog(4), 1);
+    /// assert_eq!(rounded.as_container(), &vec![2]);
+    /// let rounded = tensor.modulus_switch_with_guard(CiphertextModulusLog(4), 2);
+    /// assert_eq!(rounded.as_container(), &vec![3]);
+    /// ```
+    pub fn modulus_switch_with_guard(
+        &self,
+        log_out_modulus: CiphertextModulusLog,
+        guard_bits: usize,
+    ) -> Tensor<Vec<<Self as AsRefSlice>::Element>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: UnsignedInteger,
+    {
+        let bits = <<Self as AsRefSlice>::Element as Numeric>::BITS;
+        assert_ne!(log_out_modulus.0, 0, "The output modulus log cannot be 0.");
+        assert!(
+            log_out_modulus.0 + guard_bits <= bits,
+            "Cannot keep {} bits out of {bits} bits elements.",
+            log_out_modulus.0 + guard_bits
+        );
+        let shift = bits - log_out_modulus.0 - guard_bits;
+        let mask = if log_out_modulus.0 == bits {
+            <Self as AsRefSlice>::Element::MAX
+        } else {
+            (<Self as AsRefSlice>::Element::ONE << log_out_modulus.0)
+                - <Self as AsRefSlice>::Element::ONE
+        };
+        self.iter()
+            .map(|element| {
+                let kept = *element >> shift;
+                if guard_bits == 0 {
+                    return kept & mask;
+                }
+                let one = <Self as AsRefSlice>::Element::ONE;
+                let truncated = kept >> guard_bits;
+                let guard = kept & ((one << guard_bits) - one);
+                let half = one << (guard_bits - 1);
+                let round_up = guard > half || (guard == half && truncated & one == one);
+                let switched = if round_up {
+                    truncated.wrapping_add(one)
+                } else {
+                    truncated
+                };
+                switched & mask
+            })
+            .collect()
+    }
+
     /// Reverses the elements of the tensor inplace.
     ///
     /// # Example
@@ -1278,36 +1956,448 @@ impl<Container> Tensor<Container> {
 
     /// Rotates the elements of the tensor to the right, inplace.
     ///
+    /// The rotation is cyclic, and `n` is reduced modulo the length of the tensor, so rotating
+    /// by more than the length is allowed.
+    ///
     /// # Example
     ///
     /// ```
-    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, Tensor};
     /// let mut tensor = Tensor::from_container(vec![1u8, 2, 3, 4]);
     /// tensor.rotate_right(2);
     /// assert_eq!(*tensor.get_element(0), 3);
+    /// let mut tensor = Tensor::from_container(vec![0u8, 1, 2, 3]);
+    /// tensor.rotate_right(1);
+    /// assert_eq!(tensor.as_slice(), &[3, 0, 1, 2]);
+    /// tensor.rotate_right(9);
+    /// tensor.rotate_left(2);
+    /// assert_eq!(tensor.as_slice(), &[0, 1, 2, 3]);
     /// ```
     pub fn rotate_right(&mut self, n: usize)
     where
         Self: AsMutSlice,
     {
-        self.as_mut_slice().rotate_right(n)
+        let len = self.len();
+        if len != 0 {
+            self.as_mut_slice().rotate_right(n % len)
+        }
     }
 
     /// Rotates the elements of the tensor to the left, inplace.
     ///
+    /// The rotation is cyclic, and `n` is reduced modulo the length of the tensor, so rotating
+    /// by more than the length is allowed.
+    ///
     /// # Example
     ///
     /// ```
-    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, Tensor};
     /// let mut tensor = Tensor::from_container(vec![1u8, 2, 3, 4]);
     /// tensor.rotate_left(2);
     /// assert_eq!(*tensor.get_element(0), 3);
+    /// let mut tensor = Tensor::from_container(vec![0u8, 1, 2, 3]);
+    /// tensor.rotate_left(1);
+    /// assert_eq!(tensor.as_slice(), &[1, 2, 3, 0]);
+    /// tensor.rotate_left(9);
+    /// tensor.rotate_right(2);
+    /// assert_eq!(tensor.as_slice(), &[0, 1, 2, 3]);
     /// ```
     pub fn rotate_left(&mut self, n: usize)
     where
         Self: AsMutSlice,
     {
-        self.as_mut_slice().rotate_left(n)
+        let len = self.len();
+        if len != 0 {
+            self.as_mut_slice().rotate_left(n % len)
+        }
+    }
+
+    /// Applies the bit-reversal permutation to the elements of the tensor, inplace.
+    ///
+    /// The element at index $i$ is swapped with the element whose index is $i$ with its
+    /// $\log_2(n)$ bits reversed, as needed before an iterative radix-2 FFT or NTT.
+    ///
+    /// # Note:
+    ///
+    /// The length of the tensor must be a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let mut tensor = Tensor::from_container(vec![0u8, 1, 2, 3, 4, 5, 6, 7]);
+    /// tensor.bit_reverse_permute();
+    /// assert_eq!(tensor.as_container(), &vec![0u8, 4, 2, 6, 1, 5, 3, 7]);
+    /// ```
+    pub fn bit_reverse_permute(&mut self)
+    where
+        Self: AsMutSlice,
+    {
+        let len = self.len();
+        ck_dim_pow2!(len);
+        if len <= 1 {
+            return;
+        }
+        let shift = usize::BITS - len.trailing_zeros();
+        let slice = self.as_mut_slice();
+        for i in 0..len {
+            let j = i.reverse_bits() >> shift;
+            if i < j {
+                slice.swap(i, j);
+            }
+        }
+    }
+
+    /// Returns a permuted copy of the tensor, such that `output[i] = self[perm[i]]`.
+    ///
+    /// # Note:
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![10u8, 20, 30, 40]);
+    /// let perm = [2, 0, 3, 1];
+    /// let permuted = tensor.apply_permutation(&perm);
+    /// assert_eq!(permuted.as_container(), &vec![30u8, 10, 40, 20]);
+    /// let inverse = [1, 3, 0, 2];
+    /// let recovered = permuted.apply_permutation(&inverse);
+    /// assert_eq!(recovered, tensor);
+    /// ```
+    pub fn apply_permutation(&self, perm: &[usize]) -> Tensor<Vec<<Self as AsRefSlice>::Element>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Copy,
+    {
+        assert_eq!(
+            self.len(),
+            perm.len(),
+            "The permutation length does not match the tensor length"
+        );
+        let mut seen = vec![false; perm.len()];
+        for &index in perm.iter() {
+            assert!(
+                index < perm.len() && !seen[index],
+                "The input slice is not a permutation of 0..{}",
+                perm.len()
+            );
+            seen[index] = true;
+        }
+        let input = self.as_slice();
+        perm.iter().map(|&index| input[index]).collect()
+    }
+
+    /// Returns a tensor containing the values of `table` indexed by the values of `self`.
+    ///
+    /// The values of `self` are reduced modulo the length of `table` before being used as
+    /// indices, so that `output[i] = table[self[i] % table.len()]`.
+    ///
+    /// # Note:
+    ///
+    /// Panics if `table` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![0u8, 1, 2, 3, 4, 5, 6]);
+    /// let table = [10u8, 20, 30];
+    /// let mapped = tensor.apply_table(&table);
+    /// assert_eq!(mapped.as_container(), &vec![10u8, 20, 30, 10, 20, 30, 10]);
+    /// ```
+    pub fn apply_table(
+        &self,
+        table: &[<Self as AsRefSlice>::Element],
+    ) -> Tensor<Vec<<Self as AsRefSlice>::Element>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Copy + CastInto<u128>,
+    {
+        assert!(!table.is_empty(), "The lookup table is empty");
+        let table_len = table.len() as u128;
+        self.iter()
+            .map(|&value| {
+                let value: u128 = value.cast_into();
+                table[(value % table_len) as usize]
+            })
+            .collect()
+    }
+
+    /// Packs a tensor of zeros and ones into bytes, eight elements per byte.
+    ///
+    /// The first element is stored in the least significant bit of the first byte. When the
+    /// length of the tensor is not a multiple of 8, the last byte is padded with zeros. The
+    /// tensor can be recovered with [`unpack_bits`](`Tensor::unpack_bits`).
+    ///
+    /// # Note:
+    ///
+    /// Panics if an element of the tensor is neither zero nor one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![1u8, 0, 1, 1, 0, 1, 1, 0, 0, 1]);
+    /// let bytes = tensor.pack_bits();
+    /// assert_eq!(bytes, vec![0b0110_1101, 0b0000_0010]);
+    /// assert_eq!(Tensor::unpack_bits(&bytes, tensor.len()), tensor);
+    /// ```
+    pub fn pack_bits(&self) -> Vec<u8>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Numeric,
+    {
+        self.as_slice()
+            .chunks(8)
+            .map(|chunk| {
+                chunk.iter().enumerate().fold(0u8, |byte, (i, &value)| {
+                    if value == <Self as AsRefSlice>::Element::ONE {
+                        byte | (1 << i)
+                    } else {
+                        assert!(
+                            value == <Self as AsRefSlice>::Element::ZERO,
+                            "Tried to pack an element which is neither zero nor one"
+                        );
+                        byte
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the number of elements of the tensor which are equal to one.
+    ///
+    /// For a tensor containing the coefficients of a binary secret key, this is the Hamming
+    /// weight of the key, which can be used to check that the key is not degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![0u64, 1, 1, 0, 1, 0, 0, 1]);
+    /// assert_eq!(tensor.hamming_weight(), 4);
+    /// let zeros = Tensor::allocate(0u64, 8);
+    /// assert_eq!(zeros.hamming_weight(), 0);
+    /// ```
+    pub fn hamming_weight(&self) -> usize
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Numeric,
+    {
+        self.iter()
+            .filter(|&&value| value == <Self as AsRefSlice>::Element::ONE)
+            .count()
+    }
+
+    /// Returns the index and a reference to the first element maximizing `key`, or `None` if the
+    /// tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![3u32, 250, 7, 250]);
+    /// // Looks for the element of largest magnitude, interpreting the elements as signed bytes.
+    /// let largest = tensor.max_by_key(|value| (*value as u8 as i8).unsigned_abs());
+    /// assert_eq!(largest, Some((2, &7)));
+    /// assert_eq!(tensor.max_by_key(|value| *value), Some((1, &250)));
+    /// ```
+    pub fn max_by_key<Key, F>(&self, mut key: F) -> Option<(usize, &<Self as AsRefSlice>::Element)>
+    where
+        Self: AsRefSlice,
+        Key: Ord,
+        F: FnMut(&<Self as AsRefSlice>::Element) -> Key,
+    {
+        let mut best: Option<(usize, &<Self as AsRefSlice>::Element, Key)> = None;
+        for (index, value) in self.iter().enumerate() {
+            let value_key = key(value);
+            if best
+                .as_ref()
+                .map_or(true, |(_, _, best_key)| value_key > *best_key)
+            {
+                best = Some((index, value, value_key));
+            }
+        }
+        best.map(|(index, value, _)| (index, value))
+    }
+
+    /// Returns the index and a reference to the first element minimizing `key`, or `None` if the
+    /// tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![3u32, 250, 7, 250]);
+    /// assert_eq!(tensor.min_by_key(|value| value.abs_diff(8)), Some((2, &7)));
+    /// assert_eq!(tensor.min_by_key(|value| *value), Some((0, &3)));
+    /// ```
+    pub fn min_by_key<Key, F>(&self, mut key: F) -> Option<(usize, &<Self as AsRefSlice>::Element)>
+    where
+        Self: AsRefSlice,
+        Key: Ord,
+        F: FnMut(&<Self as AsRefSlice>::Element) -> Key,
+    {
+        let mut best: Option<(usize, &<Self as AsRefSlice>::Element, Key)> = None;
+        for (index, value) in self.iter().enumerate() {
+            let value_key = key(value);
+            if best
+                .as_ref()
+                .map_or(true, |(_, _, best_key)| value_key < *best_key)
+            {
+                best = Some((index, value, value_key));
+            }
+        }
+        best.map(|(index, value, _)| (index, value))
+    }
+
+    /// Returns the index of the first largest element, or `None` if the tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![3u32, 250, 7, 250]);
+    /// assert_eq!(tensor.argmax(), Some(1));
+    /// let empty = Tensor::from_container(Vec::<u32>::new());
+    /// assert_eq!(empty.argmax(), None);
+    /// ```
+    pub fn argmax(&self) -> Option<usize>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Ord,
+    {
+        // `Iterator::max_by` keeps the last maximum, hence the reversal.
+        self.iter()
+            .enumerate()
+            .rev()
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the first smallest element, or `None` if the tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![3u32, 250, 3, 7]);
+    /// assert_eq!(tensor.argmin(), Some(0));
+    /// let empty = Tensor::from_container(Vec::<u32>::new());
+    /// assert_eq!(empty.argmin(), None);
+    /// ```
+    pub fn argmin(&self) -> Option<usize>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Ord,
+    {
+        self.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns a new tensor containing the modular distance between the elements of two tensors.
+    ///
+    /// The distance between two elements is the smallest of the two wrapping differences, that
+    /// is the length of the shortest path between the two values on the integer circle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, Tensor};
+    /// let t1 = Tensor::from_container(vec![0u8, 10, 250, 128]);
+    /// let t2 = Tensor::from_container(vec![0u8, 7, 3, 0]);
+    /// let distance = t1.modular_distance(&t2);
+    /// assert_eq!(distance.as_slice(), &[0, 3, 9, 128]);
+    /// ```
+    pub fn modular_distance<OtherCont>(
+        &self,
+        other: &Tensor<OtherCont>,
+    ) -> Tensor<Vec<<Self as AsRefSlice>::Element>>
+    where
+        Self: AsRefSlice,
+        Tensor<OtherCont>: AsRefSlice<Element = <Self as AsRefSlice>::Element>,
+        <Self as AsRefSlice>::Element: UnsignedInteger,
+    {
+        ck_dim_eq!(self.len() => other.len());
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.wrapping_sub(*b).min(b.wrapping_sub(*a)))
+            .collect()
+    }
+
+    /// Returns a new tensor containing the modular distance between the elements of two tensors,
+    /// normalized to the torus.
+    ///
+    /// This is the [`modular_distance`](`Tensor::modular_distance`) divided by `2^BITS`, so every
+    /// value lies in `[0, 0.5]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::{AsRefSlice, Tensor};
+    /// let t1 = Tensor::from_container(vec![0u8, 64, 224, 128]);
+    /// let t2 = Tensor::from_container(vec![0u8, 0, 32, 0]);
+    /// let distance = t1.torus_modular_distance(&t2);
+    /// assert_eq!(distance.as_slice(), &[0., 0.25, 0.25, 0.5]);
+    /// ```
+    pub fn torus_modular_distance<OtherCont>(&self, other: &Tensor<OtherCont>) -> Tensor<Vec<f64>>
+    where
+        Self: AsRefSlice,
+        Tensor<OtherCont>: AsRefSlice<Element = <Self as AsRefSlice>::Element>,
+        <Self as AsRefSlice>::Element: UnsignedInteger + CastInto<f64>,
+    {
+        let scale = 2_f64.powi(<Self as AsRefSlice>::Element::BITS as i32);
+        self.modular_distance(other)
+            .into_container()
+            .into_iter()
+            .map(|distance| {
+                let distance: f64 = distance.cast_into();
+                distance / scale
+            })
+            .collect()
+    }
+
+    /// Returns whether the modular distance between every pair of elements of two tensors is
+    /// at most `tolerance`.
+    ///
+    /// This is meant to compare noisy values, say decrypted plaintexts, for which an exact
+    /// comparison is meaningless.
+    ///
+    /// # Note:
+    ///
+    /// Panics if the two tensors do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::math::tensor::Tensor;
+    /// let t1 = Tensor::from_container(vec![0u8, 10, 250, 128]);
+    /// let t2 = Tensor::from_container(vec![2u8, 7, 1, 129]);
+    /// assert!(t1.eq_within_tolerance(&t2, 7));
+    /// assert!(!t1.eq_within_tolerance(&t2, 6));
+    /// ```
+    pub fn eq_within_tolerance<OtherCont>(
+        &self,
+        other: &Tensor<OtherCont>,
+        tolerance: <Self as AsRefSlice>::Element,
+    ) -> bool
+    where
+        Self: AsRefSlice,
+        Tensor<OtherCont>: AsRefSlice<Element = <Self as AsRefSlice>::Element>,
+        <Self as AsRefSlice>::Element: UnsignedInteger,
+    {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Tried to compare tensors of different lengths."
+        );
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.wrapping_sub(*b).min(b.wrapping_sub(*a)) <= tolerance)
     }
 }
 
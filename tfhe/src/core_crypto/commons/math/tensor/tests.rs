@@ -1,4 +1,7 @@
 use super::Tensor;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::CastFrom;
+use crate::core_crypto::prelude::CiphertextModulusLog;
 
 #[test]
 fn test_add_u32() {
@@ -1255,3 +1258,343 @@ fn test_add_u64() {
 
     assert_eq!(t_3, ground_truth_t_3, "we are testing u64 add");
 }
+
+#[test]
+fn test_wrapping_scalar_mul_add_containers() {
+    let values = [0_u64, 1, 2, u64::MAX, u64::MAX / 2 + 1];
+    let factor = 3_u64;
+    let offset = u64::MAX - 1;
+    let ground_truth: Vec<u64> = values
+        .iter()
+        .map(|v| v.wrapping_mul(factor).wrapping_add(offset))
+        .collect();
+
+    // Vec
+    let mut t_vec = Tensor::from_container(values.to_vec());
+    t_vec.update_with_wrapping_scalar_mul(&factor);
+    t_vec.update_with_wrapping_scalar_add(&offset);
+    assert_eq!(t_vec.as_container(), &ground_truth, "we are testing Vec");
+
+    // &mut [T]
+    let mut buffer = values.to_vec();
+    let mut t_slice = Tensor::from_container(buffer.as_mut_slice());
+    t_slice.update_with_wrapping_scalar_mul(&factor);
+    t_slice.update_with_wrapping_scalar_add(&offset);
+    assert_eq!(buffer, ground_truth, "we are testing &mut [T]");
+
+    // AVec
+    let mut t_avec = Tensor::from_container(aligned_vec::AVec::<u64>::from_iter(
+        0,
+        values.iter().copied(),
+    ));
+    t_avec.update_with_wrapping_scalar_mul(&factor);
+    t_avec.update_with_wrapping_scalar_add(&offset);
+    assert_eq!(
+        t_avec.as_container().as_slice(),
+        ground_truth.as_slice(),
+        "we are testing AVec"
+    );
+}
+
+#[cfg(feature = "__commons_parallel")]
+#[test]
+fn test_par_subtensor_iter_matches_sequential() {
+    use rayon::iter::ParallelIterator;
+
+    let tensor: Tensor<Vec<u32>> = (0..1000_u32).collect();
+    let sequential: Vec<Vec<u32>> = tensor
+        .subtensor_iter(10)
+        .map(|sub| sub.into_container().to_vec())
+        .collect();
+    let parallel: Vec<Vec<u32>> = tensor
+        .par_subtensor_iter(10)
+        .map(|sub| sub.into_container().to_vec())
+        .collect();
+    assert_eq!(sequential, parallel);
+}
+
+#[cfg(feature = "__commons_parallel")]
+#[test]
+fn test_par_fill_with_two_matches_sequential() {
+    use crate::core_crypto::commons::test_tools::new_random_generator;
+
+    let mut generator = new_random_generator();
+    let lhs: Tensor<Vec<u64>> = generator.random_uniform_tensor(100_003);
+    let rhs: Tensor<Vec<u64>> = generator.random_uniform_tensor(100_003);
+    let ope = |a: &u64, b: &u64| a.wrapping_mul(*b).rotate_left(7) ^ b;
+
+    let mut sequential = Tensor::allocate(0_u64, lhs.len());
+    sequential.fill_with_two(&lhs, &rhs, ope);
+    for thread_count in [1, 2, 3, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(thread_count)
+            .build()
+            .unwrap();
+        let mut parallel = Tensor::allocate(0_u64, lhs.len());
+        pool.install(|| parallel.par_fill_with_two(&lhs, &rhs, ope));
+        assert_eq!(parallel, sequential, "Mismatch with {thread_count} threads");
+    }
+}
+
+#[test]
+fn test_into_chunks_remainder() {
+    use super::Split;
+
+    let mut data: Vec<u8> = (0..10).collect();
+
+    let (chunks, remainder) = data.as_slice().into_chunks_remainder(3);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(remainder, &[9]);
+
+    let (chunks, remainder) = data.as_mut_slice().into_chunks_remainder(4);
+    assert_eq!(chunks.len(), 2);
+    chunks.for_each(|chunk| chunk.fill(0));
+    remainder.fill(1);
+    assert_eq!(data, vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 1]);
+
+    let (chunks, remainder) = data.as_slice().into_chunks_remainder(5);
+    assert_eq!(chunks.len(), 2);
+    assert!(remainder.is_empty());
+}
+
+#[test]
+fn test_pack_bits_roundtrip() {
+    use crate::core_crypto::commons::test_tools::{new_random_generator, random_usize_between};
+
+    let mut generator = new_random_generator();
+    let len = 8 * random_usize_between(1..100) + random_usize_between(1..8);
+    let tensor: Tensor<Vec<u8>> = generator.random_uniform_binary_tensor(len);
+    let bytes = tensor.pack_bits();
+    assert_eq!(bytes.len(), len / 8 + 1);
+    // The padding bits of the last byte are zeros.
+    assert_eq!(bytes[len / 8] >> (len % 8), 0);
+    assert_eq!(Tensor::unpack_bits(&bytes, len), tensor);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec_container() {
+    use super::ContainerOwned;
+    use arrayvec::ArrayVec;
+
+    let mut tensor = Tensor::from_container(<ArrayVec<u64, 32> as ContainerOwned>::collect(
+        (0..32).map(|i| u64::MAX - i),
+    ));
+    let other = Tensor::from_container(<ArrayVec<u64, 32> as ContainerOwned>::collect(
+        (0..32).map(|i| 2 * i + 1),
+    ));
+    tensor.update_with_wrapping_add(&other);
+    assert!(tensor.iter().zip(0..32_u64).all(|(a, i)| *a == i));
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+#[should_panic(expected = "capacity 4")]
+fn test_arrayvec_container_overflow() {
+    use super::ContainerOwned;
+    let _ = <arrayvec::ArrayVec<u64, 4> as ContainerOwned>::collect(0..5_u64);
+}
+
+fn test_modulus_switch_with_guard<T: UnsignedTorus>() {
+    let cast = <T as CastFrom<f64>>::cast_from;
+    let log_out_modulus = CiphertextModulusLog(8);
+    let shift = T::BITS - log_out_modulus.0;
+    let one_out = T::ONE << shift;
+    let half_out = T::ONE << (shift - 1);
+    let quarter_out = T::ONE << (shift - 2);
+    // Values just above the middle of two consecutive output values, the first one even and the
+    // second one odd, and a value three quarters of the way between two output values.
+    let tensor = Tensor::from_container(vec![
+        one_out.wrapping_mul(cast(2.)) + half_out + T::ONE,
+        one_out.wrapping_mul(cast(3.)) + half_out + T::ONE,
+        one_out.wrapping_mul(cast(2.)) + half_out + quarter_out,
+        T::MAX,
+    ]);
+
+    // Without guard bits, the values are truncated
+    let truncated = tensor.modulus_switch_with_guard(log_out_modulus, 0);
+    assert_eq!(
+        truncated.as_container(),
+        &vec![cast(2.), cast(3.), cast(2.), cast(255.)]
+    );
+
+    // With a single guard bit, every value above the middle is seen as a tie, and rounded to the
+    // even output value
+    let rounded = tensor.modulus_switch_with_guard(log_out_modulus, 1);
+    assert_eq!(
+        rounded.as_container(),
+        &vec![cast(2.), cast(4.), cast(2.), T::ZERO]
+    );
+
+    // With more guard bits, the three quarters are rounded up, while the values closer to the
+    // middle are still seen as ties
+    for guard_bits in 2..4 {
+        let rounded = tensor.modulus_switch_with_guard(log_out_modulus, guard_bits);
+        assert_eq!(
+            rounded.as_container(),
+            &vec![cast(2.), cast(4.), cast(3.), T::ZERO]
+        );
+    }
+
+    // With all the remaining bits as guard bits, all the values are rounded to the closest output
+    // value, modulo the output modulus
+    let rounded = tensor.modulus_switch_with_guard(log_out_modulus, shift);
+    assert_eq!(
+        rounded.as_container(),
+        &vec![cast(3.), cast(4.), cast(3.), T::ZERO]
+    );
+}
+
+#[test]
+fn test_modulus_switch_with_guard_u32() {
+    test_modulus_switch_with_guard::<u32>();
+}
+
+#[test]
+fn test_modulus_switch_with_guard_u64() {
+    test_modulus_switch_with_guard::<u64>();
+}
+
+// Writes the values to a temporary file in native endianness, and returns its path.
+#[cfg(feature = "mmap")]
+fn write_temporary_file(name: &str, values: &[u64]) -> std::path::PathBuf {
+    use std::io::Write;
+
+    let path = std::env::temp_dir().join(format!("tfhe_{name}_{}", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    for value in values.iter() {
+        file.write_all(&value.to_ne_bytes()).unwrap();
+    }
+    path
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mapped_tensor() {
+    use super::{MappedTensor, MmapContainer};
+
+    let values: Vec<u64> = (0..1000).map(|i| u64::MAX - 3 * i).collect();
+    let path = write_temporary_file("mapped_tensor", &values);
+    let file = std::fs::File::open(&path).unwrap();
+
+    let tensor = Tensor::from_container(unsafe { MmapContainer::<u64>::map(&file) }.unwrap());
+    assert_eq!(tensor.len(), values.len());
+    for (sub, expected) in tensor.subtensor_iter(10).zip(values.chunks(10)) {
+        assert_eq!(sub.as_container(), &expected);
+    }
+    let mapped = unsafe { MappedTensor::<u64>::map(&file) }.unwrap();
+    assert_eq!(mapped.as_view().as_container(), &values.as_slice());
+
+    // The file size is not a multiple of the size of u128
+    let odd_path = write_temporary_file("mapped_tensor_odd", &values[..999]);
+    let file = std::fs::File::open(&odd_path).unwrap();
+    assert!(unsafe { MmapContainer::<u128>::map(&file) }.is_err());
+    assert!(unsafe { MappedTensor::<u128>::map(&file) }.is_err());
+
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&odd_path).unwrap();
+}
+
+#[cfg(feature = "subtle")]
+fn test_conditional_negate<T: UnsignedTorus + subtle::ConditionallySelectable>() {
+    use subtle::Choice;
+
+    let cast = <T as CastFrom<f64>>::cast_from;
+    let original =
+        Tensor::from_container((0..1000).map(|i| cast(i as f64 + 1.)).collect::<Vec<_>>());
+    let signs: Vec<Choice> = (0..1000)
+        .map(|i| Choice::from((i % 3 == 0) as u8))
+        .collect();
+    let mut tensor = original.clone();
+    tensor.conditional_negate(&signs);
+    for (i, (negated, value)) in tensor.iter().zip(original.iter()).enumerate() {
+        if i % 3 == 0 {
+            assert_eq!(*negated, value.wrapping_neg());
+        } else {
+            assert_eq!(*negated, *value);
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_conditional_negate_u32() {
+    test_conditional_negate::<u32>();
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_conditional_negate_u64() {
+    test_conditional_negate::<u64>();
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+#[should_panic(expected = "does not match the length")]
+fn test_conditional_negate_length_mismatch() {
+    let mut tensor = Tensor::allocate(1u64, 4);
+    tensor.conditional_negate(&[subtle::Choice::from(1); 3]);
+}
+
+#[test]
+#[should_panic(expected = "Tried to compare tensors of different lengths.")]
+fn test_first_difference_length_mismatch() {
+    let t1 = Tensor::allocate(1u64, 4);
+    let t2 = Tensor::allocate(1u64, 3);
+    let _ = t1.first_difference(&t2);
+}
+
+#[test]
+fn test_bit_reverse_permute_is_an_involution() {
+    for len in [2usize, 4, 8, 1024] {
+        let original = Tensor::from_container((0..len as u64).collect::<Vec<_>>());
+        let mut tensor = original.clone();
+        tensor.bit_reverse_permute();
+        // The permutation is the identity on two elements only.
+        assert_eq!(tensor == original, len == 2);
+        // The first and last indices are their own bit reversal.
+        assert_eq!(*tensor.first(), 0);
+        assert_eq!(*tensor.last(), len as u64 - 1);
+        tensor.bit_reverse_permute();
+        assert_eq!(tensor, original);
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "is not a power of two")]
+fn test_bit_reverse_permute_non_power_of_two() {
+    let mut tensor = Tensor::allocate(0u32, 12);
+    tensor.bit_reverse_permute();
+}
+
+#[test]
+fn test_split_at_bounds() {
+    let mut tensor = Tensor::from_container((0..10u32).collect::<Vec<_>>());
+    let (left, right) = tensor.split_at(0);
+    assert!(left.is_empty());
+    assert_eq!(right.len(), 10);
+    let (left, right) = tensor.split_at_mut(10);
+    assert_eq!(left.len(), 10);
+    assert!(right.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Tried to split a tensor of length 10 at index 11.")]
+fn test_split_at_out_of_range() {
+    let tensor = Tensor::allocate(0u32, 10);
+    let _ = tensor.split_at(11);
+}
+
+#[test]
+fn test_eq_within_tolerance_single_outlier() {
+    let tensor = Tensor::from_container((0..100u64).map(|i| i << 40).collect::<Vec<_>>());
+    let mut noisy = tensor.clone();
+    noisy
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, a)| *a = a.wrapping_add(i as u64 % 17).wrapping_sub(8));
+    assert!(tensor.eq_within_tolerance(&noisy, 8));
+    *noisy.get_element_mut(42) = noisy.get_element(42).wrapping_sub(9);
+    assert!(!tensor.eq_within_tolerance(&noisy, 8));
+}
@@ -0,0 +1,201 @@
+use super::UnsignedTorus;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A torus element in floating point representation.
+///
+/// The element wraps an `f64` which is always kept in $[0, 1)$, and the arithmetic operations are
+/// performed modulo 1. This makes it possible to reason on torus values directly, say to
+/// analyze the noise of ciphertexts, without converting each unsigned integer to a float first.
+///
+/// # Precision
+///
+/// An `f64` carries 53 bits of mantissa, so the elements close to 1 are only known with an
+/// absolute precision of $2^{-53}$. Converting a `u32` torus element is exact, but a `u64` torus
+/// element loses up to its 11 least significant bits, and every operation may add a rounding
+/// error of the same order. This is fine for statistics on the noise, whose magnitude is far
+/// larger, but not for computations which must be exact.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct FloatTorus(f64);
+
+impl FloatTorus {
+    /// The zero of the torus.
+    pub const ZERO: FloatTorus = FloatTorus(0.);
+
+    /// Creates a torus element from a real value, reduced modulo 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::torus::FloatTorus;
+    /// assert_eq!(FloatTorus::new(1.25).value(), 0.25);
+    /// assert_eq!(FloatTorus::new(-0.25).value(), 0.75);
+    /// ```
+    pub fn new(value: f64) -> FloatTorus {
+        let reduced = value - value.floor();
+        // The reduction of a small negative value can round up to 1.
+        if reduced >= 1. {
+            FloatTorus(0.)
+        } else {
+            FloatTorus(reduced)
+        }
+    }
+
+    /// Creates a torus element from its unsigned integer representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::torus::FloatTorus;
+    /// assert_eq!(FloatTorus::from_unsigned(1u32 << 30).value(), 0.25);
+    /// ```
+    pub fn from_unsigned<T: UnsignedTorus>(value: T) -> FloatTorus {
+        FloatTorus::new(value.into_torus())
+    }
+
+    /// Returns the value of the element, in $[0, 1)$.
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the signed distance from `other` to `self`, in $[-0.5, 0.5)$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::torus::FloatTorus;
+    /// let a = FloatTorus::new(0.125);
+    /// let b = FloatTorus::new(0.875);
+    /// assert_eq!(a.signed_distance(b), 0.25);
+    /// assert_eq!(b.signed_distance(a), -0.25);
+    /// ```
+    pub fn signed_distance(self, other: FloatTorus) -> f64 {
+        let difference = (self - other).0;
+        if difference >= 0.5 {
+            difference - 1.
+        } else {
+            difference
+        }
+    }
+
+    /// Returns the modular distance between `self` and `other`, in $[0, 0.5]$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::torus::FloatTorus;
+    /// let a = FloatTorus::new(0.125);
+    /// let b = FloatTorus::new(0.875);
+    /// assert_eq!(a.modular_distance(b), 0.25);
+    /// assert_eq!(b.modular_distance(a), 0.25);
+    /// ```
+    pub fn modular_distance(self, other: FloatTorus) -> f64 {
+        self.signed_distance(other).abs()
+    }
+}
+
+impl Add for FloatTorus {
+    type Output = FloatTorus;
+
+    fn add(self, other: FloatTorus) -> FloatTorus {
+        FloatTorus::new(self.0 + other.0)
+    }
+}
+
+impl AddAssign for FloatTorus {
+    fn add_assign(&mut self, other: FloatTorus) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for FloatTorus {
+    type Output = FloatTorus;
+
+    fn sub(self, other: FloatTorus) -> FloatTorus {
+        FloatTorus::new(self.0 - other.0)
+    }
+}
+
+impl SubAssign for FloatTorus {
+    fn sub_assign(&mut self, other: FloatTorus) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for FloatTorus {
+    type Output = FloatTorus;
+
+    fn neg(self) -> FloatTorus {
+        FloatTorus::new(-self.0)
+    }
+}
+
+/// A torus element on which the signed error with respect to another element can be computed.
+///
+/// This is implemented for the unsigned integer representations of the torus, as well as for
+/// [`FloatTorus`], so that the noise analysis tools work with both.
+pub trait TorusDistance: Copy {
+    /// Returns the signed distance from `other` to `self` on the torus, in $[-0.5, 0.5]$.
+    fn torus_distance(self, other: Self) -> f64;
+}
+
+impl<T: UnsignedTorus> TorusDistance for T {
+    fn torus_distance(self, other: Self) -> f64 {
+        let d0 = self.wrapping_sub(other);
+        let d1 = other.wrapping_sub(self);
+        if d0 < d1 {
+            d0.into_torus()
+        } else {
+            -d1.into_torus()
+        }
+    }
+}
+
+impl TorusDistance for FloatTorus {
+    fn torus_distance(self, other: Self) -> f64 {
+        self.signed_distance(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::test_tools::{any_uint, random_uint_between};
+
+    fn test_distances_match_integer_path<T: UnsignedTorus>(tolerance: f64) {
+        for _ in 0..10_000 {
+            let a: T = any_uint();
+            // Half of the pairs are close to each other, as the noisy values are.
+            let b = if random_uint_between(0u8..2) == 0 {
+                any_uint()
+            } else {
+                a.wrapping_add(random_uint_between(T::ZERO..T::ONE << (T::BITS - 8)))
+            };
+            let float = FloatTorus::from_unsigned(a).torus_distance(FloatTorus::from_unsigned(b));
+            let integer = a.torus_distance(b);
+            assert!(
+                (float - integer).abs() <= tolerance,
+                "{a} and {b}: {float} != {integer}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_distances_match_integer_path_u32() {
+        test_distances_match_integer_path::<u32>(0.);
+    }
+
+    #[test]
+    fn test_distances_match_integer_path_u64() {
+        test_distances_match_integer_path::<u64>(2_f64.powi(-52));
+    }
+
+    #[test]
+    fn test_operations_wrap_around() {
+        let a = FloatTorus::new(0.75);
+        let b = FloatTorus::new(0.5);
+        assert_eq!((a + b).value(), 0.25);
+        assert_eq!((b - a).value(), 0.75);
+        assert_eq!((-a).value(), 0.25);
+        assert_eq!(FloatTorus::new(-1e-20), FloatTorus::ZERO);
+    }
+}
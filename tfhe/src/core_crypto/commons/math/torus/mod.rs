@@ -11,6 +11,8 @@
 //! floating point representation. For this reason we provide the [`IntoTorus`] and [`FromTorus`]
 //! traits which allow to go back and forth between an unsigned integer representation and a
 //! floating point representation.
+//! When the analysis is easier to carry in floating point, the [`FloatTorus`] type represents a
+//! torus element as an `f64` reduced modulo 1.
 
 use crate::core_crypto::commons::math::random::{
     Gaussian, RandomGenerable, Uniform, UniformBinary, UniformTernary,
@@ -19,6 +21,9 @@ pub use crate::core_crypto::commons::numeric::{CastInto, FloatingPoint, Numeric,
 use crate::core_crypto::prelude::LogStandardDev;
 use std::fmt::{Debug, Display};
 
+mod float_torus;
+pub use float_torus::*;
+
 /// A trait that converts a torus element in unsigned integer representation to the closest
 /// torus element in floating point representation.
 pub trait IntoTorus<F>: Sized
@@ -100,3 +105,31 @@ impl UnsignedTorus for u32 {
 impl UnsignedTorus for u64 {
     const GAUSSIAN_KEY_LOG_STD: LogStandardDev = LogStandardDev(-62.32192809488736);
 }
+
+/// Calls a generic function once for each type implementing [`UnsignedTorus`].
+///
+/// The function is instantiated with each of the supported torus types in turn, with an
+/// increasing bit width, and called with the remaining arguments of the macro. This makes it
+/// possible to run the same generic code on all the torus types without repeating it by hand.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::math::torus::UnsignedTorus;
+/// use tfhe::for_each_torus_type;
+///
+/// fn record_bits<T: UnsignedTorus>(bits: &mut Vec<usize>) {
+///     bits.push(T::BITS);
+/// }
+///
+/// let mut bits = Vec::new();
+/// for_each_torus_type!(record_bits, &mut bits);
+/// assert_eq!(bits, vec![32, 64]);
+/// ```
+#[macro_export]
+macro_rules! for_each_torus_type {
+    ($function:ident $(, $argument:expr)* $(,)?) => {{
+        $function::<u32>($($argument),*);
+        $function::<u64>($($argument),*);
+    }};
+}
@@ -36,6 +36,64 @@ macro_rules! modular_distance {
     };
 }
 
+/// Asserts that a decrypted value is within `delta` of the expected value, modulo the integer
+/// size.
+///
+/// The check is only performed when the `noise-asserts` feature is enabled. Otherwise, the
+/// arguments are type-checked but never evaluated, and the macro compiles to nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::debug_noise_assert;
+/// let expected: u64 = 1 << 60;
+/// let decrypted: u64 = (1 << 60) - 12;
+/// debug_noise_assert!(decrypted, expected, 16);
+/// ```
+#[cfg(feature = "noise-asserts")]
+#[macro_export]
+macro_rules! debug_noise_assert {
+    ($decrypted:expr, $expected:expr, $delta:expr) => {{
+        let decrypted = $decrypted;
+        let expected = $expected;
+        let delta = $delta;
+        let distance = decrypted
+            .wrapping_sub(expected)
+            .min(expected.wrapping_sub(decrypted));
+        assert!(
+            distance <= delta,
+            "Noise bound exceeded: {} != {} +- {}",
+            decrypted,
+            expected,
+            delta
+        );
+    }};
+}
+
+/// Asserts that a decrypted value is within `delta` of the expected value, modulo the integer
+/// size.
+///
+/// The check is only performed when the `noise-asserts` feature is enabled. Otherwise, the
+/// arguments are type-checked but never evaluated, and the macro compiles to nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::debug_noise_assert;
+/// let expected: u64 = 1 << 60;
+/// let decrypted: u64 = (1 << 60) - 12;
+/// debug_noise_assert!(decrypted, expected, 16);
+/// ```
+#[cfg(not(feature = "noise-asserts"))]
+#[macro_export]
+macro_rules! debug_noise_assert {
+    ($decrypted:expr, $expected:expr, $delta:expr) => {{
+        if false {
+            let _ = (&$decrypted, &$expected, &$delta);
+        }
+    }};
+}
+
 pub mod crypto;
 pub mod math;
 pub mod numeric;
@@ -46,19 +104,28 @@ pub mod utils;
 pub mod test_tools {
     use rand::Rng;
 
+    use crate::core_crypto::commons::crypto::encoding::{
+        decode_plaintext, encode_message, Plaintext,
+    };
+    use crate::core_crypto::commons::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+    use crate::core_crypto::commons::crypto::noise_formulas::erfc;
     use crate::core_crypto::commons::crypto::secret::generators::{
-        EncryptionRandomGenerator, SecretRandomGenerator,
+        DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
     };
+    use crate::core_crypto::commons::crypto::secret::LweSecretKey;
     use crate::core_crypto::commons::math::random::{RandomGenerable, RandomGenerator, Uniform};
     use crate::core_crypto::commons::math::tensor::{AsRefSlice, AsRefTensor};
-    use crate::core_crypto::commons::math::torus::UnsignedTorus;
-    use crate::core_crypto::commons::numeric::UnsignedInteger;
+    use crate::core_crypto::commons::math::torus::{TorusDistance, UnsignedTorus};
+    use crate::core_crypto::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
     use crate::core_crypto::prelude::{
-        CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, DispersionParameter,
-        GlweDimension, LweDimension, PlaintextCount, PolynomialSize,
+        BinaryKeyKind, CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
+        DispersionParameter, GlweDimension, KeyKind, LweDimension, PlaintextCount, PolynomialSize,
+        StandardDev,
     };
     use concrete_csprng::generators::SoftwareRandomGenerator;
     use concrete_csprng::seeders::{Seed, Seeder};
+    #[cfg(feature = "__commons_serialization")]
+    use serde::{Deserialize, Serialize};
 
     fn modular_distance<T: UnsignedInteger>(first: T, other: T) -> T {
         let d0 = first.wrapping_sub(other);
@@ -66,18 +133,6 @@ pub mod test_tools {
         std::cmp::min(d0, d1)
     }
 
-    fn torus_modular_distance<T: UnsignedInteger>(first: T, other: T) -> f64 {
-        let d0 = first.wrapping_sub(other);
-        let d1 = other.wrapping_sub(first);
-        if d0 < d1 {
-            let d: f64 = d0.cast_into();
-            d / 2_f64.powi(T::BITS as i32)
-        } else {
-            let d: f64 = d1.cast_into();
-            -d / 2_f64.powi(T::BITS as i32)
-        }
-    }
-
     pub fn new_random_generator() -> RandomGenerator<SoftwareRandomGenerator> {
         RandomGenerator::new(random_seed())
     }
@@ -136,7 +191,7 @@ pub mod test_tools {
     ) where
         First: AsRefTensor<Element = Element>,
         Second: AsRefTensor<Element = Element>,
-        Element: UnsignedTorus,
+        Element: TorusDistance,
     {
         use crate::core_crypto::commons::math::tensor::Tensor;
         use rand::distributions::{Distribution, Normal};
@@ -151,7 +206,7 @@ pub mod test_tools {
 
         // recover the errors from each ciphertexts
         sdk_samples.fill_with_two(first.as_tensor(), second.as_tensor(), |a, b| {
-            torus_modular_distance(*a, *b)
+            a.torus_distance(*b)
         });
 
         // fill the theoretical sample vector according to std_dev using the rand crate
@@ -174,6 +229,93 @@ pub mod test_tools {
         );
     }
 
+    /// Checks that the errors between `first` and `second` follow a centered gaussian of the given
+    /// dispersion, with a chi-square goodness-of-fit test.
+    ///
+    /// The errors are sorted in `bin_count` buckets: the inner ones evenly split $[-3\sigma,
+    /// 3\sigma]$, and the two outer ones collect the tails. Each bucket must be expected to hold at
+    /// least 5 samples for the test to be meaningful. The test panics if the gaussian hypothesis
+    /// is rejected with the given `confidence`.
+    pub fn assert_noise_distribution_chi2<First, Second, Element>(
+        first: &First,
+        second: &Second,
+        dist: impl DispersionParameter,
+        confidence: f64,
+        bin_count: usize,
+    ) where
+        First: AsRefTensor<Element = Element>,
+        Second: AsRefTensor<Element = Element>,
+        Element: TorusDistance,
+    {
+        assert!(bin_count >= 3, "The chi-square test needs at least 3 bins");
+        assert!(
+            0. < confidence && confidence < 1.,
+            "The confidence must be in ]0, 1["
+        );
+
+        let std_dev = dist.get_standard_dev();
+        let n_slots = first.as_tensor().len();
+        let normal_cdf = |x: f64| 0.5 * erfc(-x / (std::f64::consts::SQRT_2 * std_dev));
+
+        // the bin_count - 1 edges separating the bins
+        let edges: Vec<f64> = (0..bin_count - 1)
+            .map(|i| std_dev * (-3. + 6. * i as f64 / (bin_count - 2) as f64))
+            .collect();
+
+        let mut observed = vec![0_usize; bin_count];
+        for (x, y) in first.as_tensor().iter().zip(second.as_tensor().iter()) {
+            let error = x.torus_distance(*y);
+            observed[edges.partition_point(|edge| *edge <= error)] += 1;
+        }
+
+        let mut statistic = 0.;
+        for (bin, observed) in observed.iter().enumerate() {
+            let lower = if bin == 0 {
+                0.
+            } else {
+                normal_cdf(edges[bin - 1])
+            };
+            let upper = edges.get(bin).map_or(1., |edge| normal_cdf(*edge));
+            let expected = n_slots as f64 * (upper - lower);
+            assert!(
+                expected >= 5.,
+                "Bin {} is expected to hold {} samples, use more samples or fewer bins",
+                bin,
+                expected
+            );
+            let delta = *observed as f64 - expected;
+            statistic += delta * delta / expected;
+        }
+
+        // compute the critical value with the Wilson-Hilferty approximation
+        let degrees = (bin_count - 1) as f64;
+        let quantile = normal_quantile(confidence);
+        let critical =
+            degrees * (1. - 2. / (9. * degrees) + quantile * (2. / (9. * degrees)).sqrt()).powi(3);
+        assert!(
+            statistic <= critical,
+            "Not the same distribution: the chi-square statistic {} exceeds the critical value {} \
+            at a confidence of {}",
+            statistic,
+            critical,
+            confidence
+        );
+    }
+
+    // Returns the quantile of the standard normal distribution, by bisection.
+    fn normal_quantile(probability: f64) -> f64 {
+        let (mut low, mut high) = (-10_f64, 10_f64);
+        for _ in 0..100 {
+            let mid = 0.5 * (low + high);
+            if 0.5 * erfc(-mid / std::f64::consts::SQRT_2) < probability {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        0.5 * (low + high)
+    }
+
     /// Returns a random plaintext count in [1;max].
     pub fn random_plaintext_count(max: usize) -> PlaintextCount {
         assert_ne!(max, 0, "Max cannot be 0");
@@ -253,4 +395,312 @@ pub mod test_tools {
         let mut generator = new_random_generator();
         generator.random_uniform()
     }
+
+    /// Checks that a keyswitch key switches ciphertexts from `input_key` to `output_key`.
+    ///
+    /// Over `trials` iterations, a random message is encoded with [`encode_message`], encrypted
+    /// without noise under `input_key`, keyswitched with `ks_key` and decrypted under `output_key`.
+    /// The decoded message must match the input message, so that only the noise and the
+    /// decomposition error of the keyswitch key are tested.
+    pub fn assert_keyswitch_correct<T, InKind, OutKind, KskCont, InKeyCont, OutKeyCont>(
+        ks_key: &LweKeyswitchKey<KskCont>,
+        input_key: &LweSecretKey<InKind, InKeyCont>,
+        output_key: &LweSecretKey<OutKind, OutKeyCont>,
+        message_modulus: u64,
+        carry_modulus: u64,
+        trials: usize,
+    ) where
+        T: UnsignedTorus + CastFrom<u64> + CastInto<u64>,
+        InKind: KeyKind,
+        OutKind: KeyKind,
+        LweKeyswitchKey<KskCont>: AsRefTensor<Element = T>,
+        LweSecretKey<InKind, InKeyCont>: AsRefTensor<Element = T>,
+        LweSecretKey<OutKind, OutKeyCont>: AsRefTensor<Element = T>,
+    {
+        let mut generator = new_encryption_random_generator();
+        let mut input = LweCiphertext::allocate(T::ZERO, input_key.key_size().to_lwe_size());
+        let mut output = LweCiphertext::allocate(T::ZERO, output_key.key_size().to_lwe_size());
+        for _ in 0..trials {
+            let expected = any_uint::<u64>() % message_modulus;
+            let plaintext = encode_message(expected, message_modulus, carry_modulus);
+            input_key.encrypt_lwe(&mut input, &plaintext, StandardDev(0.), &mut generator);
+            ks_key.keyswitch_ciphertext(&mut output, &input);
+            let mut decrypted = Plaintext(T::ZERO);
+            output_key.decrypt_lwe(&mut decrypted, &output);
+            let decoded = decode_plaintext(decrypted, message_modulus, carry_modulus);
+            assert_eq!(
+                decoded, expected,
+                "The keyswitched ciphertext decrypted to {decoded} instead of {expected}"
+            );
+        }
+    }
+
+    /// The number of LWE encryptions in a set of regression vectors.
+    const REGRESSION_VECTOR_COUNT: usize = 8;
+
+    /// The LWE dimension used by the regression vectors.
+    const REGRESSION_LWE_DIMENSION: LweDimension = LweDimension(16);
+
+    /// A single LWE encryption pinned down by the regression vectors.
+    #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RegressionVector {
+        pub secret_key: Vec<u64>,
+        pub plaintext: u64,
+        pub ciphertext: Vec<u64>,
+        pub decrypted: u64,
+    }
+
+    /// A set of LWE encryptions fully determined by a seed.
+    ///
+    /// The vectors can be serialized and committed, to check later on that the crate still
+    /// produces the same keys, ciphertexts and decryptions from the same seed.
+    #[cfg_attr(feature = "__commons_serialization", derive(Serialize, Deserialize))]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RegressionVectors {
+        pub seed: u128,
+        pub vectors: Vec<RegressionVector>,
+    }
+
+    /// Generates a set of regression vectors from `seed`.
+    pub fn generate_regression_vectors(seed: Seed) -> RegressionVectors {
+        let mut secret_generator = SecretRandomGenerator::<SoftwareRandomGenerator>::new(seed);
+        let mut encryption_generator = EncryptionRandomGenerator::<SoftwareRandomGenerator>::new(
+            seed,
+            &mut DeterministicSeeder::<SoftwareRandomGenerator>::new(seed),
+        );
+        let vectors = (0..REGRESSION_VECTOR_COUNT)
+            .map(|_| {
+                let secret_key =
+                    LweSecretKey::generate_binary(REGRESSION_LWE_DIMENSION, &mut secret_generator);
+                let plaintext = Plaintext(
+                    secret_generator
+                        .random_uniform_tensor::<u64>(1)
+                        .into_container()[0],
+                );
+                let mut ciphertext =
+                    LweCiphertext::allocate(0_u64, REGRESSION_LWE_DIMENSION.to_lwe_size());
+                secret_key.encrypt_lwe(
+                    &mut ciphertext,
+                    &plaintext,
+                    StandardDev(2_f64.powi(-25)),
+                    &mut encryption_generator,
+                );
+                let mut decrypted = Plaintext(0_u64);
+                secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+                RegressionVector {
+                    secret_key: secret_key.as_tensor().as_slice().to_vec(),
+                    plaintext: plaintext.0,
+                    ciphertext: ciphertext.as_tensor().as_slice().to_vec(),
+                    decrypted: decrypted.0,
+                }
+            })
+            .collect();
+        RegressionVectors {
+            seed: seed.0,
+            vectors,
+        }
+    }
+
+    /// Checks that the current implementation reproduces `expected` from its seed.
+    pub fn assert_regression_vectors_reproduced(expected: &RegressionVectors) {
+        let actual = generate_regression_vectors(Seed(expected.seed));
+        assert_eq!(
+            actual.vectors.len(),
+            expected.vectors.len(),
+            "The number of regression vectors changed"
+        );
+        for (index, (actual, expected)) in actual.vectors.iter().zip(&expected.vectors).enumerate()
+        {
+            assert_eq!(
+                actual, expected,
+                "The regression vector {index} is not reproduced"
+            );
+        }
+    }
+
+    /// Runs a probabilistic test up to `n` times, and panics only if every attempt fails.
+    ///
+    /// This is meant for statistical tests which can fail on rare tail events. Every failure is
+    /// logged on the standard error, so that a test failing more often than expected is noticed.
+    pub fn with_retries<E: std::fmt::Debug>(n: usize, test: impl Fn() -> Result<(), E>) {
+        assert_ne!(n, 0, "A test must be attempted at least once");
+        let mut failures = Vec::with_capacity(n);
+        for attempt in 1..=n {
+            match test() {
+                Ok(()) => return,
+                Err(error) => {
+                    eprintln!("Attempt {attempt}/{n} failed: {error:?}");
+                    failures.push(error);
+                }
+            }
+        }
+        panic!("All the {n} attempts failed: {failures:?}");
+    }
+
+    mod tests {
+        use super::*;
+        use crate::core_crypto::commons::math::tensor::{AsMutTensor, Tensor};
+
+        const STD_DEV: f64 = 1. / 1_048_576.;
+
+        fn gaussian_errors(mean: f64) -> Tensor<Vec<u64>> {
+            let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+            generator.random_gaussian_tensor(100_000, mean, STD_DEV)
+        }
+
+        type BinaryKey<T> = LweSecretKey<BinaryKeyKind, Vec<T>>;
+
+        fn keyswitch_setup<T: UnsignedTorus>(
+        ) -> (BinaryKey<T>, BinaryKey<T>, LweKeyswitchKey<Vec<T>>) {
+            let mut secret_generator = new_secret_random_generator();
+            let mut encryption_generator = new_encryption_random_generator();
+            let input_key = LweSecretKey::generate_binary(LweDimension(128), &mut secret_generator);
+            let output_key = LweSecretKey::generate_binary(LweDimension(64), &mut secret_generator);
+            let mut ks_key = LweKeyswitchKey::allocate(
+                T::ZERO,
+                DecompositionLevelCount(5),
+                DecompositionBaseLog(6),
+                LweDimension(128),
+                LweDimension(64),
+            );
+            ks_key.fill_with_keyswitch_key(
+                &input_key,
+                &output_key,
+                StandardDev(2_f64.powi(-40)),
+                &mut encryption_generator,
+            );
+            (input_key, output_key, ks_key)
+        }
+
+        fn test_keyswitch_correct_accepts_valid_key<T>()
+        where
+            T: UnsignedTorus + CastFrom<u64> + CastInto<u64>,
+        {
+            let (input_key, output_key, ks_key) = keyswitch_setup::<T>();
+            assert_keyswitch_correct(&ks_key, &input_key, &output_key, 4, 4, 100);
+        }
+
+        #[test]
+        fn test_keyswitch_correct_accepts_valid_key_u32() {
+            test_keyswitch_correct_accepts_valid_key::<u32>();
+        }
+
+        #[test]
+        fn test_keyswitch_correct_accepts_valid_key_u64() {
+            test_keyswitch_correct_accepts_valid_key::<u64>();
+        }
+
+        #[test]
+        #[should_panic(expected = "The keyswitched ciphertext decrypted to")]
+        fn test_keyswitch_correct_rejects_broken_key() {
+            let (input_key, output_key, mut ks_key) = keyswitch_setup::<u64>();
+            // Shifting the body of every key ciphertext by the most significant carry bit breaks
+            // the encryptions of the input key.
+            for value in ks_key.as_mut_tensor().iter_mut().skip(64).step_by(65) {
+                *value = value.wrapping_add(1 << 62);
+            }
+            assert_keyswitch_correct(&ks_key, &input_key, &output_key, 4, 4, 100);
+        }
+
+        #[cfg(feature = "__commons_serialization")]
+        #[test]
+        fn test_regression_vectors_roundtrip() {
+            let vectors = generate_regression_vectors(random_seed());
+            let json = serde_json::to_string(&vectors).unwrap();
+            let deserialized: RegressionVectors = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, vectors);
+            assert_regression_vectors_reproduced(&deserialized);
+            for vector in deserialized.vectors.iter() {
+                let distance = vector.decrypted.wrapping_sub(vector.plaintext);
+                assert!(distance.min(distance.wrapping_neg()) < 1 << 44);
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "The regression vector 0 is not reproduced")]
+        fn test_regression_vectors_detect_changes() {
+            let mut vectors = generate_regression_vectors(random_seed());
+            vectors.vectors[0].plaintext = vectors.vectors[0].plaintext.wrapping_add(1);
+            assert_regression_vectors_reproduced(&vectors);
+        }
+
+        #[test]
+        fn test_with_retries_succeeds_on_second_attempt() {
+            let attempts = std::cell::Cell::new(0);
+            with_retries(3, || {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 2 {
+                    Err("tail event")
+                } else {
+                    Ok(())
+                }
+            });
+            assert_eq!(attempts.get(), 2);
+        }
+
+        #[test]
+        #[should_panic(expected = "All the 3 attempts failed")]
+        fn test_with_retries_fails_when_every_attempt_fails() {
+            with_retries(3, || Err("real bug"));
+        }
+
+        #[test]
+        fn test_normal_quantile() {
+            assert!(normal_quantile(0.5).abs() < 1e-6);
+            assert!((normal_quantile(0.95) - 1.644_853_6).abs() < 1e-5);
+            assert!((normal_quantile(0.99) - 2.326_347_9).abs() < 1e-5);
+        }
+
+        #[test]
+        fn test_chi2_accepts_gaussian_samples() {
+            let errors = gaussian_errors(0.);
+            let zeros = Tensor::allocate(0_u64, errors.len());
+            assert_noise_distribution_chi2(&errors, &zeros, StandardDev(STD_DEV), 0.99, 20);
+        }
+
+        #[test]
+        fn test_noise_distribution_accepts_float_torus_samples() {
+            use crate::core_crypto::commons::math::torus::FloatTorus;
+
+            let errors: Vec<_> = gaussian_errors(0.)
+                .iter()
+                .map(|error| FloatTorus::from_unsigned(*error))
+                .collect();
+            let errors = Tensor::from_container(errors);
+            let zeros = Tensor::allocate(FloatTorus::ZERO, errors.len());
+            assert_noise_distribution(&errors, &zeros, StandardDev(STD_DEV));
+            assert_noise_distribution_chi2(&errors, &zeros, StandardDev(STD_DEV), 0.99, 20);
+        }
+
+        #[test]
+        #[should_panic(expected = "Not the same distribution")]
+        fn test_chi2_rejects_shifted_samples() {
+            let errors = gaussian_errors(STD_DEV / 2.);
+            let zeros = Tensor::allocate(0_u64, errors.len());
+            assert_noise_distribution_chi2(&errors, &zeros, StandardDev(STD_DEV), 0.99, 20);
+        }
+
+        #[test]
+        #[should_panic(expected = "Not the same distribution")]
+        fn test_chi2_rejects_wider_samples() {
+            let errors = gaussian_errors(0.);
+            let zeros = Tensor::allocate(0_u64, errors.len());
+            assert_noise_distribution_chi2(&errors, &zeros, StandardDev(STD_DEV / 1.1), 0.99, 20);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "noise-asserts"))]
+mod tests {
+    #[test]
+    fn test_debug_noise_assert_within_bound() {
+        debug_noise_assert!(3_u64, u64::MAX - 2, 6_u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "Noise bound exceeded")]
+    fn test_debug_noise_assert_out_of_bound() {
+        debug_noise_assert!(1000_u64, 0_u64, 16_u64);
+    }
 }
@@ -0,0 +1,75 @@
+use crate::core_crypto::prelude::Variance;
+use crate::core_crypto::specification::engines::AbstractEngine;
+use crate::core_crypto::specification::entities::{
+    LweCiphertextEntity, LweCompactPublicKeyEntity, PlaintextEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingCompactPublicKeyEncryptionError for LweCiphertextDiscardingCompactPublicKeyEncryptionEngine @
+    LweDimensionMismatch => "The public key and ciphertext LWE dimensions must be the same."
+}
+
+impl<EngineError: std::error::Error>
+    LweCiphertextDiscardingCompactPublicKeyEncryptionError<EngineError>
+{
+    /// Validates the inputs
+    pub fn perform_generic_checks<PublicKey, Ciphertext>(
+        key: &PublicKey,
+        output: &Ciphertext,
+    ) -> Result<(), Self>
+    where
+        PublicKey: LweCompactPublicKeyEntity,
+        Ciphertext: LweCiphertextEntity,
+    {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(Self::LweDimensionMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines encrypting (discarding) LWE ciphertexts with a compact public key.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// the encryption of the `input` plaintext, using the compact public `key` and errors drawn with
+/// the `noise` variance. The ciphertext can be decrypted by the secret key used to generate the
+/// public key.
+///
+/// # Formal Definition
+///
+/// Given a compact public key $(A, B)$, a random binary polynomial $U$, a polynomial $E\_1$ and a
+/// value $e\_2$ with Gaussian coefficients, the output ciphertext is made of the mask whose
+/// multisum with the secret key is the constant coefficient of $(A\cdot U + E\_1)\cdot S$, and of
+/// the body $b = (B\cdot U)\_0 + e\_2 + \mathsf{pt}$.
+pub trait LweCiphertextDiscardingCompactPublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    PublicKey: LweCompactPublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Encrypts an LWE ciphertext using a compact public key.
+    fn discard_encrypt_lwe_ciphertext_with_compact_public_key(
+        &mut self,
+        key: &PublicKey,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingCompactPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext using a compact public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingCompactPublicKeyEncryptionError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_encrypt_lwe_ciphertext_with_compact_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+        noise: Variance,
+    );
+}
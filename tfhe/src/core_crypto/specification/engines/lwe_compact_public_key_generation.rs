@@ -0,0 +1,52 @@
+use crate::core_crypto::prelude::{LweDimension, Variance};
+use crate::core_crypto::specification::engines::AbstractEngine;
+use crate::core_crypto::specification::entities::{LweCompactPublicKeyEntity, LweSecretKeyEntity};
+
+engine_error! {
+    LweCompactPublicKeyGenerationError for LweCompactPublicKeyGenerationEngine @
+    NonPowerOfTwoLweDimension => "The LWE dimension of the secret key must be a power of two."
+}
+
+impl<EngineError: std::error::Error> LweCompactPublicKeyGenerationError<EngineError> {
+    /// Validates the inputs
+    pub fn perform_generic_checks(lwe_dimension: LweDimension) -> Result<(), Self> {
+        if !lwe_dimension.0.is_power_of_two() {
+            return Err(Self::NonPowerOfTwoLweDimension);
+        }
+        Ok(())
+    }
+}
+
+/// A trait for engines generating new compact LWE public keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a new compact LWE public key.
+///
+/// # Formal Definition
+///
+/// cf [`here`](`crate::core_crypto::specification::entities::LweCompactPublicKeyEntity`)
+pub trait LweCompactPublicKeyGenerationEngine<SecretKey, PublicKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    PublicKey: LweCompactPublicKeyEntity,
+{
+    /// Generates a new compact LWE public key.
+    fn generate_new_lwe_compact_public_key(
+        &mut self,
+        lwe_secret_key: &SecretKey,
+        noise: Variance,
+    ) -> Result<PublicKey, LweCompactPublicKeyGenerationError<Self::EngineError>>;
+
+    /// Unsafely generates a new compact LWE public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCompactPublicKeyGenerationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn generate_new_lwe_compact_public_key_unchecked(
+        &mut self,
+        lwe_secret_key: &SecretKey,
+        noise: Variance,
+    ) -> PublicKey;
+}
@@ -167,6 +167,7 @@ mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bit_extraction;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_compact_public_key_encryption;
 mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_keyswitch;
@@ -183,6 +184,7 @@ mod lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_packing;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation;
+mod lwe_compact_public_key_generation;
 mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_generation;
 mod lwe_public_key_generation;
@@ -210,6 +212,7 @@ pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bit_extraction::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
+pub use lwe_ciphertext_discarding_compact_public_key_encryption::*;
 pub use lwe_ciphertext_discarding_conversion::*;
 pub use lwe_ciphertext_discarding_encryption::*;
 pub use lwe_ciphertext_discarding_keyswitch::*;
@@ -226,6 +229,7 @@ pub use lwe_ciphertext_vector_discarding_circuit_bootstrap_boolean_vertical_pack
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys_generation::*;
+pub use lwe_compact_public_key_generation::*;
 pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_generation::*;
 pub use lwe_public_key_generation::*;
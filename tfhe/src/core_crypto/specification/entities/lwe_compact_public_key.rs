@@ -0,0 +1,20 @@
+use crate::core_crypto::prelude::LweDimension;
+use crate::core_crypto::specification::entities::markers::LweCompactPublicKeyKind;
+use crate::core_crypto::specification::entities::AbstractEntity;
+
+/// A trait implemented by types embodying a compact LWE public key.
+///
+/// # Formal Definition
+///
+/// ## Compact LWE Public Key
+///
+/// A compact LWE public key contains a single encryption of 0 in the ring
+/// $\mathcal{R}\_q = \mathbb{Z}\_q\[X\]/(X^n+1)$, under the secret key
+/// $\vec{s}\in\mathbb{Z}\_q^n$ seen as a polynomial $S\in\mathcal{R}\_q$, where $n$ is the LWE
+/// dimension of the ciphertexts encrypted with the key. It is made of a uniformly random mask
+/// $A\in\mathcal{R}\_q$ and of a body $B = A\cdot S + E\in\mathcal{R}\_q$, where $E$ has
+/// coefficients drawn from a Gaussian distribution.
+pub trait LweCompactPublicKeyEntity: AbstractEntity<Kind = LweCompactPublicKeyKind> {
+    /// Returns the LWE dimension of the key.
+    fn lwe_dimension(&self) -> LweDimension;
+}
@@ -40,6 +40,8 @@ entity_kind_marker! {
             => "An empty type representing the GLWE ciphertext kind in the type system.",
         LwePublicKeyKind
             => "An empty type representing the LWE public key kind in the type system.",
+        LweCompactPublicKeyKind
+            => "An empty type representing the compact LWE public key kind in the type system.",
         LweSecretKeyKind
             => "An empty type representing the LWE secret key kind in the type system.",
         GlweSecretKeyKind
@@ -41,6 +41,7 @@ mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
 mod lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys;
+mod lwe_compact_public_key;
 mod lwe_keyswitch_key;
 mod lwe_public_key;
 mod lwe_secret_key;
@@ -54,6 +55,7 @@ pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
 pub use lwe_circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
+pub use lwe_compact_public_key::*;
 pub use lwe_keyswitch_key::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
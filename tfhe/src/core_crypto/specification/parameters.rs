@@ -120,6 +120,43 @@ impl GlweDimension {
 pub struct PolynomialSize(pub usize);
 
 impl PolynomialSize {
+    /// Returns the polynomial size $2^{\mathrm{log2}}$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::PolynomialSize;
+    /// assert_eq!(PolynomialSize::new_power_of_two(10), PolynomialSize(1024));
+    /// ```
+    pub fn new_power_of_two(log2: usize) -> PolynomialSize {
+        assert!(
+            log2 < usize::BITS as usize,
+            "A polynomial size of 2^{log2} does not fit in a usize"
+        );
+        PolynomialSize(1 << log2)
+    }
+
+    /// Panics if the polynomial size is not a power of two.
+    ///
+    /// The negacyclic FFT, and every algorithm built on it, relies on the polynomial size being a
+    /// power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::PolynomialSize;
+    /// PolynomialSize(1024).assert_power_of_two();
+    /// let result = std::panic::catch_unwind(|| PolynomialSize(10).assert_power_of_two());
+    /// assert!(result.is_err());
+    /// ```
+    pub fn assert_power_of_two(&self) {
+        assert!(
+            self.0.is_power_of_two(),
+            "The polynomial size {} is not a power of two",
+            self.0
+        );
+    }
+
     /// Returns the associated [`PolynomialSizeLog`].
     pub fn log2(&self) -> PolynomialSizeLog {
         PolynomialSizeLog((self.0 as f64).log2().ceil() as usize)
@@ -108,6 +108,25 @@ impl Boolean {
         BooleanClientKey(tmp_boolean_engine.create_client_key(parameters.0.to_owned()))
     }
 
+    #[wasm_bindgen]
+    pub fn new_client_key_from_seed_bytes_and_parameters(
+        seed: &[u8],
+        parameters: &BooleanParameters,
+    ) -> Result<BooleanClientKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        let seed = js_wasm_seeder::seed_from_bytes(seed)
+            .map_err(|e| wasm_bindgen::JsError::new(e.as_str()))?;
+
+        let constant_seeder = Box::new(js_wasm_seeder::ConstantSeeder::new(seed));
+
+        let mut tmp_boolean_engine =
+            crate::boolean::engine::CpuBooleanEngine::new_from_seeder(constant_seeder);
+
+        Ok(BooleanClientKey(
+            tmp_boolean_engine.create_client_key(parameters.0.to_owned()),
+        ))
+    }
+
     #[wasm_bindgen]
     pub fn new_client_key(parameters: &BooleanParameters) -> BooleanClientKey {
         set_hook(Box::new(console_error_panic_hook::hook));
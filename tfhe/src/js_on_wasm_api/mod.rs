@@ -24,6 +24,20 @@ pub(self) mod js_wasm_seeder {
         }
     }
 
+    /// Builds a seed from its little-endian byte representation.
+    ///
+    /// Returns an error describing the problem if `bytes` does not contain exactly
+    /// `SEED_BYTES_COUNT` bytes.
+    pub fn seed_from_bytes(bytes: &[u8]) -> Result<Seed, String> {
+        let bytes: [u8; SEED_BYTES_COUNT] = bytes.try_into().map_err(|_| {
+            format!(
+                "Invalid seed length: expected {SEED_BYTES_COUNT} bytes, got {}",
+                bytes.len()
+            )
+        })?;
+        Ok(Seed(u128::from_le_bytes(bytes)))
+    }
+
     impl Seeder for ConstantSeeder {
         fn seed(&mut self) -> Seed {
             self.seed
@@ -37,3 +51,68 @@ pub(self) mod js_wasm_seeder {
         }
     }
 }
+
+#[cfg(all(test, feature = "boolean-client-js-wasm-api"))]
+mod tests {
+    use super::js_wasm_seeder::{seed_from_bytes, ConstantSeeder};
+    use crate::boolean::engine::CpuBooleanEngine;
+    use crate::boolean::parameters::DEFAULT_PARAMETERS;
+
+    #[test]
+    fn test_seed_from_bytes_checks_length() {
+        assert!(seed_from_bytes(&[0u8; 15]).is_err());
+        assert!(seed_from_bytes(&[0u8; 17]).is_err());
+        assert_eq!(
+            seed_from_bytes(&[1u8; 16]).unwrap().0,
+            u128::from_le_bytes([1u8; 16])
+        );
+    }
+
+    #[test]
+    fn test_boolean_client_key_from_same_seed_is_identical() {
+        let seed_bytes: Vec<u8> = (0..16).collect();
+        let mut serialized_keys = (0..2).map(|_| {
+            let seeder = Box::new(ConstantSeeder::new(seed_from_bytes(&seed_bytes).unwrap()));
+            let mut engine = CpuBooleanEngine::new_from_seeder(seeder);
+            bincode::serialize(&engine.create_client_key(DEFAULT_PARAMETERS)).unwrap()
+        });
+        assert_eq!(serialized_keys.next(), serialized_keys.next());
+    }
+}
+
+#[cfg(all(test, feature = "shortint-client-js-wasm-api"))]
+mod shortint_tests {
+    use super::shortint::{Shortint, ShortintClientKey};
+    use crate::core_crypto::prelude::LweDimension;
+    use crate::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    use crate::shortint::{ClientKey, CompactPublicKey};
+
+    #[test]
+    fn test_compact_public_key_roundtrip() {
+        // DISCLAIMER: Note that this parameter is not guaranteed to be secure
+        let parameters = Parameters {
+            lwe_dimension: LweDimension(1024),
+            ..PARAM_MESSAGE_2_CARRY_2
+        };
+        let client_key = ClientKey::new(parameters);
+        let js_client_key = ShortintClientKey(client_key.clone());
+
+        let public_key = Shortint::new_compact_public_key(&js_client_key).unwrap();
+        let serialized = Shortint::serialize_shortint_compact_public_key(&public_key).unwrap();
+        let deserialized = Shortint::deserialize_shortint_compact_public_key(&serialized).unwrap();
+        assert_eq!(deserialized.0, public_key.0);
+
+        for message in 0..4 {
+            let ciphertext = Shortint::encrypt_with_compact_public_key(&deserialized, message);
+            assert_eq!(client_key.decrypt(&ciphertext.0), message);
+        }
+
+        // The LWE dimension of the predefined parameters is not a power of two. A `JsError` can
+        // only be built on wasm targets, so the error forwarded by the binding is checked natively.
+        let js_client_key = ShortintClientKey(ClientKey::new(PARAM_MESSAGE_2_CARRY_2));
+        #[cfg(target_arch = "wasm32")]
+        assert!(Shortint::new_compact_public_key(&js_client_key).is_err());
+        #[cfg(not(target_arch = "wasm32"))]
+        assert!(CompactPublicKey::new(&js_client_key.0).is_err());
+    }
+}
@@ -14,6 +14,9 @@ pub struct ShortintClientKey(pub(crate) crate::shortint::ClientKey);
 #[wasm_bindgen]
 pub struct ShortintPublicKey(pub(crate) crate::shortint::PublicKey);
 
+#[wasm_bindgen]
+pub struct ShortintCompactPublicKey(pub(crate) crate::shortint::CompactPublicKey);
+
 #[wasm_bindgen]
 pub struct ShortintServerKey(pub(crate) crate::shortint::ServerKey);
 
@@ -78,6 +81,16 @@ impl Shortint {
         .map(ShortintParameters)
     }
 
+    #[wasm_bindgen]
+    pub fn get_shortint_parameters_from_name(name: &str) -> Result<ShortintParameters, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        crate::shortint::parameters::get_parameters_from_name(name)
+            .ok_or_else(|| {
+                wasm_bindgen::JsError::new(format!("No parameters named '{name}'").as_str())
+            })
+            .map(ShortintParameters)
+    }
+
     #[wasm_bindgen]
     #[allow(clippy::too_many_arguments)]
     pub fn new_shortint_parameters(
@@ -160,6 +173,17 @@ impl Shortint {
         ShortintPublicKey(crate::shortint::public_key::PublicKey::new(&client_key.0))
     }
 
+    #[wasm_bindgen]
+    pub fn new_compact_public_key(
+        client_key: &ShortintClientKey,
+    ) -> Result<ShortintCompactPublicKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+
+        crate::shortint::public_key::CompactPublicKey::new(&client_key.0)
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{}", e).as_str()))
+            .map(ShortintCompactPublicKey)
+    }
+
     #[wasm_bindgen]
     pub fn new_server_key(client_key: &ShortintClientKey) -> ShortintServerKey {
         set_hook(Box::new(console_error_panic_hook::hook));
@@ -185,6 +209,16 @@ impl Shortint {
         ShortintCiphertext(public_key.0.encrypt(&server_key.0, message))
     }
 
+    #[wasm_bindgen]
+    pub fn encrypt_with_compact_public_key(
+        public_key: &ShortintCompactPublicKey,
+        message: u64,
+    ) -> ShortintCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+
+        ShortintCiphertext(public_key.0.encrypt(message))
+    }
+
     #[wasm_bindgen]
     pub fn decrypt(client_key: &ShortintClientKey, ct: &ShortintCiphertext) -> u64 {
         set_hook(Box::new(console_error_panic_hook::hook));
@@ -242,6 +276,25 @@ impl Shortint {
             .map(ShortintPublicKey)
     }
 
+    #[wasm_bindgen]
+    pub fn serialize_shortint_compact_public_key(
+        public_key: &ShortintCompactPublicKey,
+    ) -> Result<Vec<u8>, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        bincode::serialize(&public_key.0)
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{:?}", e).as_str()))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize_shortint_compact_public_key(
+        buffer: &[u8],
+    ) -> Result<ShortintCompactPublicKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        bincode::deserialize(buffer)
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{:?}", e).as_str()))
+            .map(ShortintCompactPublicKey)
+    }
+
     #[wasm_bindgen]
     pub fn serialize_shortint_server_key(
         server_key: &ShortintServerKey,
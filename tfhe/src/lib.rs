@@ -3,6 +3,7 @@
     feature = "backend_fft_nightly_avx512",
     feature(stdsimd, avx512_target_feature)
 )]
+#![cfg_attr(feature = "nightly-simd", feature(portable_simd))]
 
 #[cfg(feature = "__c_api")]
 pub mod c_api;
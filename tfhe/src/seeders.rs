@@ -61,6 +61,14 @@ pub fn new_seeder() -> Box<dyn Seeder> {
             }
         }
 
+        #[cfg(feature = "getrandom-seeder")]
+        {
+            use crate::core_crypto::commons::math::random::GetRandomSeeder;
+            if seeder.is_none() && GetRandomSeeder::is_available() {
+                seeder = Some(Box::new(GetRandomSeeder));
+            }
+        }
+
         #[cfg(not(feature = "__c_api"))]
         {
             err_msg = "Unable to instantiate a seeder, make sure to enable a seeder feature \
@@ -3,7 +3,7 @@ use super::{EngineResult, ShortintEngine};
 use crate::core_crypto::prelude::*;
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
-use crate::shortint::{Ciphertext, ClientKey, PublicKey, ServerKey};
+use crate::shortint::{Ciphertext, ClientKey, CompactPublicKey, PublicKey, ServerKey};
 
 // We have q = 2^64 so log2q = 64
 const LOG2_Q_64: usize = 64;
@@ -198,4 +198,61 @@ impl ShortintEngine {
             carry_modulus: public_key.parameters.carry_modulus,
         })
     }
+
+    pub(crate) fn new_compact_public_key(
+        &mut self,
+        client_key: &ClientKey,
+    ) -> EngineResult<CompactPublicKey> {
+        Ok(CompactPublicKey {
+            lwe_compact_public_key: self.engine.generate_new_lwe_compact_public_key(
+                &client_key.lwe_secret_key,
+                Variance(client_key.parameters.lwe_modular_std_dev.get_variance()),
+            )?,
+            parameters: client_key.parameters.to_owned(),
+        })
+    }
+
+    pub(crate) fn encrypt_with_compact_public_key(
+        &mut self,
+        public_key: &CompactPublicKey,
+        message: u64,
+    ) -> EngineResult<Ciphertext> {
+        let parameters = public_key.parameters;
+
+        //The delta is the one defined by the parameters
+        let delta =
+            (1_u64 << 63) / (parameters.message_modulus.0 * parameters.carry_modulus.0) as u64;
+
+        //The input is reduced modulus the message_modulus
+        let m = message % parameters.message_modulus.0 as u64;
+
+        let shifted_message = m * delta;
+        // encode the message
+        let plain: Plaintext64 = self.engine.create_plaintext_from(&shifted_message)?;
+
+        // This allocates the required ct
+        let mut encrypted_ct = self.engine.trivially_encrypt_lwe_ciphertext(
+            public_key
+                .lwe_compact_public_key
+                .lwe_dimension()
+                .to_lwe_size(),
+            &plain,
+        )?;
+
+        // encryption
+        self.engine
+            .discard_encrypt_lwe_ciphertext_with_compact_public_key(
+                &public_key.lwe_compact_public_key,
+                &mut encrypted_ct,
+                &plain,
+                Variance(parameters.lwe_modular_std_dev.get_variance()),
+            )?;
+
+        Ok(Ciphertext {
+            ct: encrypted_ct,
+            degree: Degree(parameters.message_modulus.0 - 1),
+            message_modulus: parameters.message_modulus,
+            carry_modulus: parameters.carry_modulus,
+        })
+    }
 }
@@ -2,9 +2,11 @@ use crate::shortint::parameters::parameters_wopbs::*;
 use crate::shortint::parameters::parameters_wopbs_message_carry::*;
 use crate::shortint::parameters::parameters_wopbs_prime_moduli::*;
 use crate::shortint::parameters::*;
+#[cfg(not(feature = "__wasm_api"))]
 use crate::shortint::wopbs::WopbsKey;
 use crate::shortint::{ClientKey, ServerKey};
 use lazy_static::*;
+#[cfg(not(feature = "__wasm_api"))]
 use serde::{Deserialize, Serialize};
 
 pub use utils::{
@@ -386,6 +388,7 @@ pub struct SharedKey {
     inner: GenericSharedKey<(ClientKey, ServerKey)>,
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 pub struct SharedWopbsKey {
     inner: GenericSharedKey<(ClientKey, ServerKey)>,
     wopbs: GenericSharedKey<WopbsKey>,
@@ -400,6 +403,7 @@ impl SharedKey {
     }
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 impl SharedWopbsKey {
     pub fn client_key(&self) -> &ClientKey {
         &self.inner.0
@@ -420,15 +424,18 @@ impl Keycache {
     }
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WopbsParamPair(pub Parameters, pub Parameters);
 
+#[cfg(not(feature = "__wasm_api"))]
 impl From<(Parameters, Parameters)> for WopbsParamPair {
     fn from(tuple: (Parameters, Parameters)) -> Self {
         Self(tuple.0, tuple.1)
     }
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 impl From<WopbsParamPair> for WopbsKey {
     fn from(params: WopbsParamPair) -> Self {
         // use with_key to avoid doing a temporary cloning
@@ -438,6 +445,7 @@ impl From<WopbsParamPair> for WopbsKey {
     }
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 impl NamedParam for WopbsParamPair {
     fn name(&self) -> String {
         self.1.name()
@@ -448,10 +456,12 @@ impl NamedParam for WopbsParamPair {
 ///
 /// You should not create an instance yourself,
 /// but rather use the global variable defined: [KEY_CACHE_WOPBS]
+#[cfg(not(feature = "__wasm_api"))]
 pub struct KeycacheWopbsV0 {
     inner: TKeyCache<WopbsParamPair, WopbsKey, FileStorage>,
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 impl Default for KeycacheWopbsV0 {
     fn default() -> Self {
         Self {
@@ -460,6 +470,7 @@ impl Default for KeycacheWopbsV0 {
     }
 }
 
+#[cfg(not(feature = "__wasm_api"))]
 impl KeycacheWopbsV0 {
     pub fn get_from_param<T: Into<WopbsParamPair>>(&self, params: T) -> SharedWopbsKey {
         let params = params.into();
@@ -474,5 +485,9 @@ impl KeycacheWopbsV0 {
 
 lazy_static! {
     pub static ref KEY_CACHE: Keycache = Default::default();
+}
+
+#[cfg(not(feature = "__wasm_api"))]
+lazy_static! {
     pub static ref KEY_CACHE_WOPBS: KeycacheWopbsV0 = Default::default();
 }
@@ -90,6 +90,30 @@ impl Parameters {
             carry_modulus,
         }
     }
+
+    /// Return the message modulus of the parameter set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{MessageModulus, PARAM_MESSAGE_3_CARRY_1};
+    /// assert_eq!(PARAM_MESSAGE_3_CARRY_1.message_modulus(), MessageModulus(8));
+    /// ```
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    /// Return the carry modulus of the parameter set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{CarryModulus, PARAM_MESSAGE_3_CARRY_1};
+    /// assert_eq!(PARAM_MESSAGE_3_CARRY_1.carry_modulus(), CarryModulus(2));
+    /// ```
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
 }
 
 impl Default for Parameters {
@@ -809,6 +833,88 @@ pub const PARAM_MESSAGE_8_CARRY_0: Parameters = Parameters {
     carry_modulus: CarryModulus(1),
 };
 
+macro_rules! named_parameters {
+    ($($name:ident),* $(,)?) => {
+        [$((stringify!($name), $name)),*]
+    };
+}
+
+/// Vector containing all the named parameter sets, along with their names.
+///
+/// The names are the identifiers of the corresponding constants, e.g. `"PARAM_MESSAGE_2_CARRY_2"`
+/// for [`PARAM_MESSAGE_2_CARRY_2`].
+pub const NAMED_PARAMETER_VEC: [(&str, Parameters); 36] = named_parameters!(
+    PARAM_MESSAGE_1_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_1,
+    PARAM_MESSAGE_2_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_2,
+    PARAM_MESSAGE_2_CARRY_1,
+    PARAM_MESSAGE_3_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_3,
+    PARAM_MESSAGE_2_CARRY_2,
+    PARAM_MESSAGE_3_CARRY_1,
+    PARAM_MESSAGE_4_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_4,
+    PARAM_MESSAGE_2_CARRY_3,
+    PARAM_MESSAGE_3_CARRY_2,
+    PARAM_MESSAGE_4_CARRY_1,
+    PARAM_MESSAGE_5_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_5,
+    PARAM_MESSAGE_2_CARRY_4,
+    PARAM_MESSAGE_3_CARRY_3,
+    PARAM_MESSAGE_4_CARRY_2,
+    PARAM_MESSAGE_5_CARRY_1,
+    PARAM_MESSAGE_6_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_6,
+    PARAM_MESSAGE_2_CARRY_5,
+    PARAM_MESSAGE_3_CARRY_4,
+    PARAM_MESSAGE_4_CARRY_3,
+    PARAM_MESSAGE_5_CARRY_2,
+    PARAM_MESSAGE_6_CARRY_1,
+    PARAM_MESSAGE_7_CARRY_0,
+    PARAM_MESSAGE_1_CARRY_7,
+    PARAM_MESSAGE_2_CARRY_6,
+    PARAM_MESSAGE_3_CARRY_5,
+    PARAM_MESSAGE_4_CARRY_4,
+    PARAM_MESSAGE_5_CARRY_3,
+    PARAM_MESSAGE_6_CARRY_2,
+    PARAM_MESSAGE_7_CARRY_1,
+    PARAM_MESSAGE_8_CARRY_0,
+);
+
+/// Return the parameter set whose constant is named `name`, if any.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{
+///     get_parameters_from_name, NAMED_PARAMETER_VEC, PARAM_MESSAGE_2_CARRY_2,
+/// };
+/// assert_eq!(
+///     get_parameters_from_name("PARAM_MESSAGE_2_CARRY_2"),
+///     Some(PARAM_MESSAGE_2_CARRY_2)
+/// );
+/// assert_eq!(get_parameters_from_name("PARAM_MESSAGE_9_CARRY_9"), None);
+///
+/// for (name, parameters) in NAMED_PARAMETER_VEC {
+///     assert_eq!(get_parameters_from_name(name), Some(parameters));
+///     assert_eq!(
+///         name,
+///         format!(
+///             "PARAM_MESSAGE_{}_CARRY_{}",
+///             parameters.message_modulus.0.ilog2(),
+///             parameters.carry_modulus.0.ilog2()
+///         )
+///     );
+/// }
+/// ```
+pub fn get_parameters_from_name(name: &str) -> Option<Parameters> {
+    NAMED_PARAMETER_VEC
+        .iter()
+        .find(|(parameters_name, _)| *parameters_name == name)
+        .map(|(_, parameters)| *parameters)
+}
+
 /// Return a parameter set from a message and carry moduli.
 ///
 /// # Example
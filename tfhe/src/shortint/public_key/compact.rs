@@ -0,0 +1,184 @@
+//! Module with the definition of the CompactPublicKey.
+use crate::core_crypto::prelude::*;
+use crate::shortint::ciphertext::Ciphertext;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::Parameters;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Debug, Display, Formatter};
+
+/// Error returned when a compact public key can not be generated.
+#[derive(Debug)]
+pub enum CompactPublicKeyError {
+    NonPowerOfTwoLweDimension(LweDimension),
+}
+
+impl Display for CompactPublicKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompactPublicKeyError::NonPowerOfTwoLweDimension(lwe_dimension) => {
+                write!(
+                    f,
+                    "The LWE dimension {} of the parameters is not a power of two",
+                    lwe_dimension.0
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompactPublicKeyError {}
+
+/// A structure containing a compact public key.
+///
+/// Contrary to the [`PublicKey`](`super::PublicKey`), which stores many LWE encryptions of zero,
+/// the compact public key stores a single encryption of zero in a polynomial ring, and is much
+/// smaller. Its ciphertexts are also less noisy, so that they can be used without being
+/// bootstrapped first.
+///
+/// # Note
+///
+/// The polynomial ring used by the key is built from the LWE dimension of the parameters, and only
+/// provides the expected security when this dimension is a power of two. Most predefined
+/// parameter sets do not fulfill this condition, in which case the key generation returns an
+/// error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompactPublicKey {
+    pub(crate) lwe_compact_public_key: LweCompactPublicKey64,
+    pub parameters: Parameters,
+}
+
+impl CompactPublicKey {
+    /// Generates a compact public key.
+    ///
+    /// Returns an error if the LWE dimension of the parameters of the client key is not a power of
+    /// two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::LweDimension;
+    /// use tfhe::shortint::client_key::ClientKey;
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::public_key::CompactPublicKey;
+    ///
+    /// // The LWE dimension of the predefined parameters is not a power of two:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// assert!(CompactPublicKey::new(&cks).is_err());
+    ///
+    /// // DISCLAIMER: Note that this parameter is not guaranteed to be secure
+    /// let parameters = Parameters {
+    ///     lwe_dimension: LweDimension(1024),
+    ///     ..PARAM_MESSAGE_2_CARRY_2
+    /// };
+    /// let cks = ClientKey::new(parameters);
+    ///
+    /// let pk = CompactPublicKey::new(&cks).unwrap();
+    ///
+    /// // The key can be serialized, and ciphertexts encrypted with the deserialized key can be
+    /// // decrypted with the client key:
+    /// let serialized = bincode::serialize(&pk).unwrap();
+    /// let deserialized: CompactPublicKey = bincode::deserialize(&serialized).unwrap();
+    /// assert_eq!(pk, deserialized);
+    /// let ct = deserialized.encrypt(1);
+    /// assert_eq!(cks.decrypt(&ct), 1);
+    /// ```
+    pub fn new(client_key: &ClientKey) -> Result<CompactPublicKey, CompactPublicKeyError> {
+        let lwe_dimension = client_key.parameters.lwe_dimension;
+        if !lwe_dimension.0.is_power_of_two() {
+            return Err(CompactPublicKeyError::NonPowerOfTwoLweDimension(
+                lwe_dimension,
+            ));
+        }
+        Ok(ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compact_public_key(client_key).unwrap()
+        }))
+    }
+
+    /// Encrypts a small integer message using the compact public key.
+    ///
+    /// The input message is reduced to the encrypted message space modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::LweDimension;
+    /// use tfhe::shortint::parameters::{Parameters, PARAM_MESSAGE_2_CARRY_2};
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// // Generate the client key:
+    /// // DISCLAIMER: Note that this parameter is not guaranteed to be secure
+    /// let parameters = Parameters {
+    ///     lwe_dimension: LweDimension(1024),
+    ///     ..PARAM_MESSAGE_2_CARRY_2
+    /// };
+    /// let cks = ClientKey::new(parameters);
+    ///
+    /// let pk = CompactPublicKey::new(&cks).unwrap();
+    ///
+    /// // Encryption of one message that is within the encrypted message modulus:
+    /// let msg = 3;
+    /// let ct = pk.encrypt(msg);
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// assert_eq!(msg, dec);
+    ///
+    /// // Encryption of one message that is outside the encrypted message modulus:
+    /// let msg = 5;
+    /// let ct = pk.encrypt(msg);
+    ///
+    /// let dec = cks.decrypt(&ct);
+    /// let modulus = cks.parameters.message_modulus.0 as u64;
+    /// assert_eq!(msg % modulus, dec);
+    /// ```
+    pub fn encrypt(&self, message: u64) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_with_compact_public_key(self, message)
+                .unwrap()
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializableCompactPublicKey {
+    lwe_compact_public_key: Vec<u8>,
+    parameters: Parameters,
+}
+
+impl Serialize for CompactPublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_eng = DefaultSerializationEngine::new(()).map_err(serde::ser::Error::custom)?;
+
+        let lwe_compact_public_key = ser_eng
+            .serialize(&self.lwe_compact_public_key)
+            .map_err(serde::ser::Error::custom)?;
+
+        SerializableCompactPublicKey {
+            lwe_compact_public_key,
+            parameters: self.parameters,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactPublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let thing = SerializableCompactPublicKey::deserialize(deserializer)
+            .map_err(serde::de::Error::custom)?;
+        let mut de_eng = DefaultSerializationEngine::new(()).map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            lwe_compact_public_key: de_eng
+                .deserialize(thing.lwe_compact_public_key.as_slice())
+                .map_err(serde::de::Error::custom)?,
+            parameters: thing.parameters,
+        })
+    }
+}
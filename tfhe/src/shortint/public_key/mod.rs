@@ -1,4 +1,8 @@
 //! Module with the definition of the PublicKey.
+mod compact;
+
+pub use compact::{CompactPublicKey, CompactPublicKeyError};
+
 use crate::core_crypto::prelude::*;
 use crate::shortint::ciphertext::Ciphertext;
 use crate::shortint::engine::ShortintEngine;